@@ -0,0 +1,133 @@
+use crate::app::COMMANDS;
+use std::collections::HashMap;
+
+/// Short names every config gets, as in k9s; `[aliases]` can redefine them
+pub const BUILTIN_ALIASES: &[(&str, &str)] = &[
+    ("r", "runs"),
+    ("p", "pipelines"),
+    ("ctx", "contexts"),
+    ("a", "aliases"),
+];
+
+/// How deep aliases may expand into one another before it's treated as a loop
+const MAX_DEPTH: usize = 10;
+
+/// Split a command line into the command name and its arguments
+pub fn split_command(input: &str) -> (&str, &str) {
+    let input = input.trim().trim_start_matches(':');
+    input
+        .split_once(char::is_whitespace)
+        .map_or((input, ""), |(name, args)| (name, args.trim()))
+}
+
+fn is_command(name: &str) -> bool {
+    COMMANDS
+        .iter()
+        .any(|(usage, _)| usage.split_whitespace().next() == Some(name))
+}
+
+fn lookup<'a>(aliases: &'a HashMap<String, String>, name: &str) -> Option<&'a str> {
+    aliases.get(name).map(String::as_str).or_else(|| {
+        BUILTIN_ALIASES
+            .iter()
+            .find(|(alias, _)| *alias == name)
+            .map(|(_, command)| *command)
+    })
+}
+
+/// Replace a leading alias with what it stands for, keeping the arguments after it
+///
+/// `f = "runs failed"` turns `f job:etl` into `runs failed job:etl`. Anything that
+/// isn't an alias is returned as is, for the command itself to accept or reject.
+pub fn expand(aliases: &HashMap<String, String>, input: &str) -> Result<String, String> {
+    let mut expanded = input.trim().trim_start_matches(':').to_string();
+    for _ in 0..MAX_DEPTH {
+        let (name, args) = split_command(&expanded);
+        if is_command(name) {
+            return Ok(expanded);
+        }
+        let Some(command) = lookup(aliases, name) else {
+            return Ok(expanded);
+        };
+        expanded = format!("{} {}", command.trim().trim_start_matches(':'), args)
+            .trim()
+            .to_string();
+    }
+    Err(format!("Alias loop in :{}", split_command(input).0))
+}
+
+/// Built-in and configured aliases, sorted by name
+pub fn all(aliases: &HashMap<String, String>) -> Vec<(String, String)> {
+    let mut all: HashMap<String, String> = BUILTIN_ALIASES
+        .iter()
+        .map(|(alias, command)| (alias.to_string(), command.to_string()))
+        .collect();
+    all.extend(aliases.clone());
+    let mut all: Vec<_> = all.into_iter().collect();
+    all.sort();
+    all
+}
+
+/// Check the `[aliases]` config: one-word names that don't hide a command,
+/// expanding to a command without looping
+pub fn validate(aliases: &HashMap<String, String>) -> Result<(), String> {
+    let mut names: Vec<&String> = aliases.keys().collect();
+    names.sort();
+
+    let errors: Vec<String> = names
+        .into_iter()
+        .filter_map(|name| {
+            if name.is_empty() || name.contains(char::is_whitespace) || name.starts_with(':') {
+                return Some(format!("`{}` must be a single word", name));
+            }
+            if is_command(name) {
+                return Some(format!("`{}` would hide the :{} command", name, name));
+            }
+            match expand(aliases, name) {
+                Err(e) => Some(e),
+                Ok(expanded) if !is_command(split_command(&expanded).0) => Some(format!(
+                    "`{}` expands to `{}`, which isn't a command",
+                    name, expanded
+                )),
+                Ok(_) => None,
+            }
+        })
+        .collect();
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_aliases() {
+        let aliases: HashMap<String, String> = toml::from_str(
+            r#"
+            f = "runs failed"
+            nightly = "r job:nightly_etl"
+            loop = "loop"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            expand(&aliases, "f job:etl").unwrap(),
+            "runs failed job:etl"
+        );
+        assert_eq!(expand(&aliases, "nightly").unwrap(), "runs job:nightly_etl");
+        assert_eq!(expand(&aliases, ":ctx").unwrap(), "contexts");
+        assert_eq!(expand(&aliases, "bogus").unwrap(), "bogus");
+        assert!(expand(&aliases, "loop").is_err());
+
+        let error = validate(&aliases).unwrap_err();
+        assert_eq!(error, "Alias loop in :loop");
+        let shadowing = HashMap::from([("runs".to_string(), "pipelines".to_string())]);
+        assert!(validate(&shadowing).is_err());
+    }
+}
@@ -1,14 +1,29 @@
 use crate::KeyAction;
-use crate::config::{Config, ContextConfig};
+use crate::aliases;
+use crate::client::DagsterClient;
+use crate::command_line::{CommandEdit, CommandLine};
+use crate::config::{Config, ContextConfig, ContextMode, RunColumn, SavedView};
+use crate::delete_run::delete_run;
+use crate::error::DagsterError;
+use crate::keys::Keymap;
+use crate::reexecute_run::reexecute_run_from_failure;
+use crate::session::{ContextSession, Session};
+use crate::terminate_run::terminate_run;
+use crate::theme::Theme;
+use crate::utils::{copy_to_clipboard, is_run_active, is_run_finished, run_statuses, truncate};
 use crate::views::{
-    ContextsView, DefaultView, PipelinesView, Run, RunPoller, RunView, RunsView, ViewPoller,
-    ViewType, ViewUI,
+    ContextsView, DebugLogView, DefaultView, PipelinesView, Run, RunPoller, RunView, RunsView,
+    ViewPoller, ViewType, ViewUI, row_run_id,
 };
-use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
+use std::path::Path;
 use std::sync::Arc;
-use tokio::sync::Mutex;
-use tokio::time::{Duration, interval};
+use std::time::{Instant, SystemTime};
+use tokio::sync::{Mutex, Notify};
+use tokio::task::JoinSet;
+use tokio::time::{Duration, sleep};
 
 // Core Data Structures
 
@@ -17,32 +32,72 @@ pub struct App {
     // View state
     pub view: ViewType,
     pub view_history: Vec<ViewType>,
-    pub next_view: Option<(ViewType, bool)>, 
-    pub view_state_cache: HashMap<ViewType, (usize, usize)>, 
+    pub next_view: Option<(ViewType, bool)>,
+    pub view_state_cache: HashMap<ViewType, ViewState>,
     pub run_view: Option<RunView>,
 
     // UI state
     pub selected_index: usize,
     pub list_offset: usize,
+    pub sort: Option<SortState>,
     pub items: Vec<String>,
     pub unfiltered_items: Vec<String>,
+    pub marked_runs: HashSet<String>,
+    /// Statuses the runs list is limited to, from filters like `:runs failed`
+    pub run_statuses: Vec<String>,
+    pub visual_anchor: Option<usize>, // Start of the visual range while in visual mode
+    pub status_message: Option<String>,
 
     // Input state
     pub command_mode: bool,
-    pub command_input: String,
+    pub command_line: CommandLine,
     pub search_mode: bool,
     pub search_input: String,
     pub has_committed_filter: bool,
+    pub confirmation: Option<Confirmation>,
+    pub pending_bulk_action: Option<PendingBulkAction>,
+    pub show_error: bool,
+    pub error_scroll: usize,
+    /// The `?` overlay listing the keys and commands for the current view and mode, or `:aliases`
+    pub help: Option<HelpPage>,
+    pub help_scroll: usize,
 
     // Data and connection state
-    pub dagster_url: String,
+    /// Client for the current context, rebuilt whenever the context changes
+    pub client: DagsterClient,
     pub connection_status: ConnectionStatus,
+    /// Most recent request failure, shown in full in the error panel
+    pub last_error: Option<DagsterError>,
+    /// When the current view last received fresh data
+    pub last_updated: Option<Instant>,
+    /// Consecutive failed polls, used for backoff
+    pub poll_failures: u32,
+    /// Whether the terminal window has focus; unfocused terminals poll less often
+    pub focused: bool,
+    /// Treat the context as read-only for this session, whatever its mode
+    pub force_read_only: bool,
+    /// Background polling is suspended; manual refreshes still work
+    pub paused: bool,
+    /// A manual refresh is waiting for the poller
+    pub refresh_requested: bool,
+    /// Wakes the poller early for manual refreshes and resumes
+    pub poll_wakeup: Arc<Notify>,
     pub state: Arc<Mutex<AppState>>, // Shared state for thread communication
     pub config: Config,
+    /// Colors from the `[theme]` config
+    pub theme: Theme,
+    /// Key bindings from the `[keys]` config
+    pub keymap: Keymap,
+    /// Modification time of the config file when it was last checked for changes
+    pub config_checked: Option<SystemTime>,
+    /// Views to reopen on startup, or None if `restore_session` is off
+    pub session: Option<Session>,
+    /// `:q` was run; the main loop exits once it sees this
+    pub quit_requested: bool,
 }
 
 /// Data state shared between threads
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct AppState {
     pub runs: Vec<Run>,
     pub pipelines: Vec<crate::get_pipelines::Pipeline>,
@@ -50,101 +105,223 @@ pub struct AppState {
     pub selected_pipeline: Option<String>,
 }
 
+/// What the help overlay shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HelpPage {
+    /// Keys and commands for the current view and input mode
+    Keys,
+    /// Built-in and configured command aliases
+    Aliases,
+    /// Views saved with `:save`
+    Views,
+}
+
+/// A mutation that can be applied to one or more runs at once
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulkAction {
+    Terminate,
+    ReexecuteFromFailure,
+    Delete,
+}
+
+impl BulkAction {
+    /// Short description used in confirmation prompts
+    pub fn verb(&self) -> &'static str {
+        match self {
+            BulkAction::Terminate => "Terminate",
+            BulkAction::ReexecuteFromFailure => "Re-execute from failure",
+            BulkAction::Delete => "Delete",
+        }
+    }
+
+    /// Send this action for one run, returning the ID of the run it affected
+    async fn apply(self, client: &DagsterClient, run_id: String) -> Result<String, DagsterError> {
+        match self {
+            BulkAction::Terminate => terminate_run(client, run_id).await,
+            BulkAction::ReexecuteFromFailure => reexecute_run_from_failure(client, run_id).await,
+            BulkAction::Delete => delete_run(client, run_id).await,
+        }
+    }
+}
+
+/// Runs a bulk action sends requests for at the same time
+const MAX_CONCURRENT_MUTATIONS: usize = 4;
+
+/// A confirmed bulk action waiting to be sent without the app lock held
+#[derive(Debug, Clone)]
+pub struct PendingBulkAction {
+    pub action: BulkAction,
+    pub run_ids: Vec<String>,
+    /// The client of the context the action was confirmed in
+    pub client: DagsterClient,
+}
+
+/// A bulk action waiting for the user to confirm it
+#[derive(Debug, Clone)]
+pub struct Confirmation {
+    pub action: BulkAction,
+    pub run_ids: Vec<String>,
+    pub summary: Vec<String>, // One line per affected run
+    pub typed: bool,          // Irreversible actions must be confirmed by typing, not a single key
+    pub input: String,
+}
+
+impl Confirmation {
+    /// Whether the typed input confirms the action: "yes", or the run ID for a single run
+    pub fn is_confirmed_by_input(&self) -> bool {
+        let input = self.input.trim();
+        input == "yes" || (self.run_ids.len() == 1 && input == self.run_ids[0])
+    }
+}
+
 /// Connection status enum for displaying in the UI
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub enum ConnectionStatus {
     Connected,
     Failed(String),
+    #[default]
     Disconnected,
 }
 
-/// Column configuration for run list views
+/// Column configuration for list views
 #[derive(Debug, Clone, Copy)]
 pub struct ColumnConfig {
     pub width: usize,
     pub min_width: usize,
     pub priority: usize, // Lower = higher priority when space is limited
     pub name: &'static str,
+    pub sort_column: SortColumn,
 }
 
-/// Collection of column configurations for run views
+/// Ordered collection of column configurations for a list view
 pub struct ColumnsConfig {
-    pub run_id: ColumnConfig,
-    pub pipeline: ColumnConfig,
-    pub status: ColumnConfig,
-    pub duration: ColumnConfig,
-    pub start_time: ColumnConfig,
+    pub columns: Vec<ColumnConfig>,
+}
+
+/// A column of the pipelines table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PipelineColumn {
+    Name,
+    RepositoryLocation,
+    LastRunStatus,
+}
+
+impl PipelineColumn {
+    /// Every column, in display order
+    pub const ALL: [PipelineColumn; 3] = [
+        PipelineColumn::Name,
+        PipelineColumn::RepositoryLocation,
+        PipelineColumn::LastRunStatus,
+    ];
+}
+
+/// A column a list can be sorted by, saved by name so it survives changes to `runs_columns`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SortColumn {
+    Run(RunColumn),
+    Pipeline(PipelineColumn),
+}
+
+/// Active sort for a list view: the column and direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SortState {
+    pub column: SortColumn,
+    pub descending: bool,
+}
+
+/// Per-view state remembered when navigating away and back
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ViewState {
+    pub selected_index: usize,
+    pub list_offset: usize,
+    pub sort: Option<SortState>,
+}
+
+/// Width and hiding priority for each runs table column
+fn run_column_config(column: RunColumn) -> ColumnConfig {
+    let (width, min_width, priority) = match column {
+        RunColumn::RunId => (36, 36, 1),
+        RunColumn::Pipeline => (30, 10, 2),
+        RunColumn::Duration => (15, 8, 3),
+        RunColumn::Status => (15, 7, 4),
+        RunColumn::StartTime => (25, 12, 5),
+        RunColumn::EndTime => (25, 12, 6),
+        RunColumn::LaunchedBy => (20, 10, 7),
+        RunColumn::Partition => (20, 10, 8),
+        RunColumn::Tags => (40, 15, 9),
+        RunColumn::CodeLocation => (25, 10, 10),
+        RunColumn::Mode => (10, 7, 11),
+    };
+
+    ColumnConfig {
+        width,
+        min_width,
+        priority,
+        name: column.title(),
+        sort_column: SortColumn::Run(column),
+    }
 }
 
 impl ColumnsConfig {
-    pub fn new() -> Self {
+    /// Columns for the runs and pipeline runs views, in the given display order
+    pub fn new(run_columns: &[RunColumn]) -> Self {
         Self {
-            run_id: ColumnConfig {
-                width: 36,
-                min_width: 36,
-                priority: 1,
-                name: "RUN ID",
-            },
-            pipeline: ColumnConfig {
-                width: 30,
-                min_width: 10,
-                priority: 2,
-                name: "PIPELINE",
-            },
-            duration: ColumnConfig {
-                width: 15,
-                min_width: 8,
-                priority: 3,
-                name: "DURATION",
-            },
-            status: ColumnConfig {
-                width: 15,
-                min_width: 7,
-                priority: 4,
-                name: "STATUS",
-            },
-            start_time: ColumnConfig {
-                width: 25,
-                min_width: 12,
-                priority: 5,
-                name: "START TIME",
-            },
-        }
-    }
-
-    pub fn get_ordered_columns(&self) -> Vec<&ColumnConfig> {
-        vec![
-            &self.run_id,
-            &self.pipeline,
-            &self.status,
-            &self.duration,
-            &self.start_time,
-        ]
+            columns: run_columns.iter().map(|&c| run_column_config(c)).collect(),
+        }
+    }
+
+    /// Columns for the pipelines view
+    pub fn pipelines() -> Self {
+        Self {
+            columns: vec![
+                ColumnConfig {
+                    width: 50,
+                    min_width: 20,
+                    priority: 1,
+                    name: "PIPELINE NAME",
+                    sort_column: SortColumn::Pipeline(PipelineColumn::Name),
+                },
+                ColumnConfig {
+                    width: 33,
+                    min_width: 15,
+                    priority: 3,
+                    name: "REPOSITORY LOCATION",
+                    sort_column: SortColumn::Pipeline(PipelineColumn::RepositoryLocation),
+                },
+                ColumnConfig {
+                    width: 15,
+                    min_width: 8,
+                    priority: 2,
+                    name: "LAST RUN STATUS",
+                    sort_column: SortColumn::Pipeline(PipelineColumn::LastRunStatus),
+                },
+            ],
+        }
     }
 
     /// Calculates column widths based on available space, respecting priorities.
     /// If there's not enough space for all columns, those with lower priority are hidden first.
     /// Any extra space is distributed to columns that need it most.
+    /// Widths are returned in display order; hidden columns get a width of 0.
     pub fn calculate_widths(&self, available_width: u16) -> Vec<usize> {
-        let total_spacing = 4; // 4 spaces between 5 columns
-        let available = available_width as usize - total_spacing;
+        let total_spacing = self.columns.len().saturating_sub(1); // One space between columns
+        let available = (available_width as usize).saturating_sub(total_spacing);
 
-        let mut columns = self.get_ordered_columns();
-        columns.sort_by_key(|c| c.priority);
+        // Column indices ordered from highest to lowest priority
+        let mut by_priority: Vec<usize> = (0..self.columns.len()).collect();
+        by_priority.sort_by_key(|&i| self.columns[i].priority);
 
-        let mut widths: Vec<usize> = columns.iter().map(|c| c.min_width).collect();
+        let mut widths: Vec<usize> = self.columns.iter().map(|c| c.min_width).collect();
         let min_total: usize = widths.iter().sum();
 
         if available <= min_total {
             let mut current_total = min_total;
-            for col in columns.iter().rev() {
+            for &idx in by_priority.iter().rev() {
                 if current_total <= available {
                     break;
                 }
-                let idx = columns
-                    .iter()
-                    .position(|c| c.priority == col.priority)
-                    .unwrap();
                 current_total -= widths[idx];
                 widths[idx] = 0;
             }
@@ -152,23 +329,24 @@ impl ColumnsConfig {
             let extra_space = available - min_total;
             let mut remaining_space = extra_space;
 
-            for (i, col) in columns.iter().enumerate() {
+            for &idx in &by_priority {
                 if remaining_space == 0 {
                     break;
                 }
-                let current = widths[i];
-                let desired = col.width;
+                let current = widths[idx];
+                let desired = self.columns[idx].width;
                 if current < desired {
                     let extra = (desired - current).min(remaining_space);
-                    widths[i] += extra;
+                    widths[idx] += extra;
                     remaining_space -= extra;
                 }
             }
 
             if remaining_space > 0 {
-                for i in 0..widths.len() {
-                    if widths[i] > 0 {
-                        widths[i] += remaining_space / widths.len();
+                let share = remaining_space / widths.len();
+                for width in widths.iter_mut() {
+                    if *width > 0 {
+                        *width += share;
                     }
                 }
             }
@@ -176,39 +354,33 @@ impl ColumnsConfig {
 
         widths
     }
-}
-
-// Default Implementations
-
-impl Default for ConnectionStatus {
-    fn default() -> Self {
-        Self::Disconnected
-    }
-}
-
-impl Default for AppState {
-    fn default() -> Self {
-        Self {
-            runs: vec![],
-            pipelines: vec![],
-            cursor: String::new(),
-            selected_pipeline: None,
-        }
-    }
-}
 
-impl Default for App {
-    fn default() -> Self {
-        Self::new()
+    /// Builds the header line for the given widths, marking the sorted column
+    pub fn header(&self, widths: &[usize], sort: Option<SortState>) -> String {
+        self.columns
+            .iter()
+            .zip(widths.iter())
+            .filter(|&(_, width)| *width > 0)
+            .map(|(col, &width)| {
+                let name = match sort {
+                    Some(sort) if sort.column == col.sort_column => {
+                        format!("{} {}", col.name, if sort.descending { "▼" } else { "▲" })
+                    }
+                    _ => col.name.to_string(),
+                };
+                format!("{:<width$}", truncate(&name, width), width = width)
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
     }
 }
 
 // App Implementation - Core Functionality
 
 impl App {
-    /// Create a new application instance with default settings
-    pub fn new() -> Self {
-        let config = Config::load().unwrap_or_default();
+    /// Create a new application instance for the given config, keeping command history and
+    /// the session in `state_dir`. Nothing is read or written there without one.
+    pub fn new(config: Config, state_dir: Option<&Path>) -> Self {
         let current_context = config.get_current_context();
 
         let mut app = Self {
@@ -222,23 +394,54 @@ impl App {
             // UI state
             selected_index: 0,
             list_offset: 0,
+            sort: None,
             items: Vec::new(),
             unfiltered_items: Vec::new(),
+            marked_runs: HashSet::new(),
+            run_statuses: Vec::new(),
+            visual_anchor: None,
+            status_message: None,
 
             // Input state
             command_mode: false,
-            command_input: String::new(),
+            command_line: state_dir
+                .map(|dir| CommandLine::with_history(dir.join("history")))
+                .unwrap_or_default(),
             search_mode: false,
             search_input: String::new(),
             has_committed_filter: false,
+            confirmation: None,
+            pending_bulk_action: None,
+            show_error: false,
+            error_scroll: 0,
+            help: None,
+            help_scroll: 0,
 
             // Data and connection state
-            dagster_url: current_context.url,
+            client: DagsterClient::failed(&current_context, "Not connected yet".to_string()),
             connection_status: ConnectionStatus::Disconnected,
+            last_error: None,
+            last_updated: None,
+            poll_failures: 0,
+            focused: true,
+            force_read_only: false,
+            paused: false,
+            refresh_requested: false,
+            poll_wakeup: Arc::new(Notify::new()),
             state: Arc::new(Mutex::new(AppState::default())),
+            // Invalid themes are reported at startup, before the TUI starts
+            theme: config.theme.resolve().unwrap_or_default(),
+            keymap: Keymap::new(&config.keys).unwrap_or_default(),
+            session: state_dir
+                .filter(|_| config.restore_session)
+                .map(|dir| Session::load(dir.join("session.json"))),
+            quit_requested: false,
+            config_checked: config.modified(),
             config,
         };
 
+        app.connect_current_context();
+
         // Populate help text
         DefaultView::populate_help_text(&mut app);
 
@@ -251,11 +454,26 @@ impl App {
         action: KeyAction,
         viewport_height: usize,
     ) -> Result<(), Box<dyn Error>> {
+        // Status messages only last until the next meaningful key press
+        if !matches!(action, KeyAction::Ignored) {
+            self.status_message = None;
+        }
+
+        // The context's safety mode gates every mutation in one place
+        let mode = self.context_mode();
+        if action.is_mutation() && mode == ContextMode::ReadOnly {
+            self.status_message = Some(format!(
+                "Context '{}' is read-only",
+                self.config.last_context
+            ));
+            return Ok(());
+        }
+
         match action {
             // Input mode actions
             KeyAction::ToggleCommandMode => self.toggle_command_mode(),
-            KeyAction::UpdateCommandInput(c) => self.command_input.push(c),
-            KeyAction::ClearCommandInput => self.clear_command_input(),
+            KeyAction::EditCommand(edit) => self.edit_command(edit),
+            KeyAction::CompleteCommand => self.complete_command().await,
             KeyAction::ExecuteCommand => self.execute_command().await,
 
             // Search actions
@@ -266,6 +484,7 @@ impl App {
             KeyAction::ClearSearchInput => self.clear_search_input(),
 
             // Navigation actions
+            KeyAction::NavigateBack if self.has_marks() => self.clear_marks(),
             KeyAction::NavigateBack => {
                 if let Err(e) = self.navigate_back().await {
                     log::error!("Error navigating back: {:?}", e);
@@ -289,35 +508,195 @@ impl App {
             KeyAction::AddContext => self.add_context(),
             KeyAction::DeleteContext => self.delete_context(),
 
+            // Polling actions
+            KeyAction::Refresh => self.request_refresh(),
+            KeyAction::TogglePause => self.toggle_pause(),
+
+            // Error panel actions
+            KeyAction::ToggleErrorPanel => {
+                self.show_error = !self.show_error;
+                self.error_scroll = 0;
+            }
+            KeyAction::ScrollDown if self.show_error => self.error_scroll += 1,
+            KeyAction::ScrollUp if self.show_error => {
+                self.error_scroll = self.error_scroll.saturating_sub(1)
+            }
+
+            // Help overlay actions
+            KeyAction::ToggleHelp => {
+                self.help = match self.help {
+                    Some(_) => None,
+                    None => Some(HelpPage::Keys),
+                };
+                self.help_scroll = 0;
+            }
+            KeyAction::ScrollDown if self.help.is_some() => self.help_scroll += 1,
+            KeyAction::ScrollUp if self.help.is_some() => {
+                self.help_scroll = self.help_scroll.saturating_sub(1)
+            }
+
             // Scrolling actions
             KeyAction::ScrollDown => self.scroll_down(),
             KeyAction::ScrollUp => self.scroll_up(),
             KeyAction::ScrollLeft => self.scroll_left(),
             KeyAction::ScrollRight => self.scroll_right(),
 
+            // Sorting actions
+            KeyAction::CycleSortColumn => self.cycle_sort_column().await,
+            KeyAction::ToggleSortOrder => self.toggle_sort_order().await,
+
+            // Marking and bulk actions
+            KeyAction::ToggleMark => self.toggle_mark(),
+            KeyAction::ToggleVisualMode => self.toggle_visual_mode(),
+            KeyAction::CopyRunIds => self.copy_run_ids(),
+            KeyAction::RequestBulkAction(bulk_action) => {
+                self.request_bulk_action(bulk_action).await;
+
+                // Unrestricted contexts skip the prompt for reversible actions
+                if mode == ContextMode::Unrestricted
+                    && self.confirmation.as_ref().is_some_and(|c| !c.typed)
+                {
+                    self.confirm_action();
+                }
+            }
+            KeyAction::UpdateConfirmInput(c) => self.update_confirm_input(c),
+            KeyAction::ClearConfirmInput => {
+                if let Some(confirmation) = &mut self.confirmation {
+                    confirmation.input.pop();
+                }
+            }
+            KeyAction::SubmitConfirmation => self.submit_confirmation(),
+            KeyAction::CancelConfirmation => self.cancel_confirmation(),
+
             // No-op actions
             KeyAction::Ignored | KeyAction::Quit => {}
         }
         Ok(())
     }
 
+    /// The current context's safety mode, after any `--readonly` override
+    pub fn context_mode(&self) -> ContextMode {
+        if self.force_read_only {
+            ContextMode::ReadOnly
+        } else {
+            self.config.get_current_context().mode
+        }
+    }
+
+    /// Show a failed request in the status bar and keep its full text for the error panel
+    pub fn report_error(&mut self, error: DagsterError) {
+        self.connection_status = ConnectionStatus::Failed(error.to_string());
+        self.last_error = Some(error);
+    }
+
     /// Start background polling for various views
     pub async fn start_polling(app: Arc<Mutex<App>>) {
-        let mut interval = interval(Duration::from_secs(3));
-
         loop {
-            interval.tick().await;
+            let (delay, wakeup) = {
+                let app_lock = app.lock().await;
+                (
+                    app_lock.next_poll_delay().await,
+                    app_lock.poll_wakeup.clone(),
+                )
+            };
+            tokio::select! {
+                _ = sleep(delay) => {}
+                _ = wakeup.notified() => {}
+            }
 
             let (view, poller) = {
-                let app_lock = app.lock().await;
+                let mut app_lock = app.lock().await;
+                let forced = std::mem::take(&mut app_lock.refresh_requested);
+                if app_lock.paused && !forced {
+                    continue;
+                }
                 (app_lock.view.clone(), App::get_poller(&app_lock.view))
             };
 
-            log::debug!("Polling for view: {:?}", view);
+            if view.poll_key().is_none() {
+                continue;
+            }
+
+            log::debug!("Polling {:?} view after {:.1}s", view, delay.as_secs_f64());
 
-            if let Err(e) = poller.poll(app.clone()).await {
+            let result = poller.poll(app.clone()).await;
+            if let Err(e) = &result {
                 log::error!("Polling error for {:?} view: {:?}", view, e);
             }
+
+            let mut app_lock = app.lock().await;
+            // The user may have moved on while the request was in flight
+            if app_lock.view == view {
+                app_lock.record_poll(result.is_ok());
+            }
+        }
+    }
+
+    /// How long to wait before the next poll of the current view
+    ///
+    /// Failed polls back off exponentially. Otherwise views with runs in
+    /// progress poll faster, and views where everything has finished, or an
+    /// unfocused terminal, poll slower.
+    async fn next_poll_delay(&self) -> Duration {
+        let polling = &self.config.polling;
+        let interval = polling.interval(self.view.poll_key());
+
+        if self.poll_failures > 0 {
+            let factor = 2f64.powi(self.poll_failures.min(16) as i32);
+            return interval.mul_f64(factor).min(polling.max_backoff());
+        }
+
+        if !self.focused {
+            return interval.max(polling.idle_interval());
+        }
+
+        let statuses: Vec<String> = match &self.view {
+            ViewType::Runs | ViewType::PipelineRuns(_) => {
+                let state = self.state.lock().await;
+                state.runs.iter().map(|run| run.status.clone()).collect()
+            }
+            ViewType::Run(_) => self
+                .run_view
+                .as_ref()
+                .and_then(|run_view| run_view.details.as_ref())
+                .map(|run| vec![run.status.clone()])
+                .unwrap_or_default(),
+            _ => Vec::new(),
+        };
+
+        if statuses.iter().any(|status| is_run_active(status)) {
+            interval.min(polling.active_interval())
+        } else if !statuses.is_empty() && statuses.iter().all(|status| is_run_finished(status)) {
+            interval.max(polling.idle_interval())
+        } else {
+            interval
+        }
+    }
+
+    /// Poll the current view right away
+    fn request_refresh(&mut self) {
+        self.refresh_requested = true;
+        self.poll_failures = 0;
+        self.poll_wakeup.notify_one();
+    }
+
+    /// Suspend or resume background polling, refreshing immediately on resume
+    fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+        if self.paused {
+            self.status_message = Some("Polling paused, r to refresh, p to resume".to_string());
+        } else {
+            self.poll_wakeup.notify_one();
+        }
+    }
+
+    /// Track poll outcomes for the backoff and the "updated" indicator
+    fn record_poll(&mut self, ok: bool) {
+        if ok {
+            self.poll_failures = 0;
+            self.last_updated = Some(Instant::now());
+        } else {
+            self.poll_failures = self.poll_failures.saturating_add(1);
         }
     }
 
@@ -330,6 +709,7 @@ impl App {
             ViewType::Contexts => Box::new(ContextsView::new()),
             ViewType::Pipelines => Box::new(PipelinesView::new()),
             ViewType::PipelineRuns(_) => Box::new(RunsView::new()),
+            ViewType::DebugLog => Box::new(DebugLogView::new()),
         }
     }
 }
@@ -342,6 +722,18 @@ impl App {
         &mut self,
         view_type: ViewType,
         reset_history: bool,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.enter_view_with_statuses(view_type, reset_history, Vec::new())
+            .await
+    }
+
+    /// Enter a new view, listing only runs with `statuses` if it's a runs list, so the
+    /// first fetch is already filtered
+    async fn enter_view_with_statuses(
+        &mut self,
+        view_type: ViewType,
+        reset_history: bool,
+        statuses: Vec<String>,
     ) -> Result<(), Box<dyn Error + Send + Sync>> {
         // Skip unnecessary view changes
         if self.view == view_type {
@@ -368,11 +760,19 @@ impl App {
         self.has_committed_filter = false;
         self.unfiltered_items.clear();
 
+        // Marks and status filters survive a peek at run details, but not a change of list
+        if !matches!(view_type, ViewType::Run(_)) {
+            self.clear_marks();
+            self.run_statuses = statuses;
+        }
+
         // Handle view history
         self.update_view_history(view_type.clone(), reset_history);
 
         // Set the new view
         self.view = view_type.clone();
+        self.last_updated = None;
+        self.poll_failures = 0;
 
         // Log the current history stack for debugging
         let history_str: Vec<String> = self
@@ -396,6 +796,9 @@ impl App {
             ViewType::Pipelines => {
                 PipelinesView::new().restore_state(self).await?;
             }
+            ViewType::DebugLog => {
+                DebugLogView::new().restore_state(self).await?;
+            }
             ViewType::PipelineRuns(_) => {
                 // Handle showing runs for a specific pipeline
                 RunsView::new().restore_state(self).await?;
@@ -410,6 +813,10 @@ impl App {
             }
         }
 
+        if self.view.poll_key().is_some() {
+            self.last_updated = Some(Instant::now());
+        }
+
         Ok(())
     }
 
@@ -434,6 +841,11 @@ impl App {
         if let Some(previous_view) = self.view_history.pop() {
             log::debug!("Navigating back to {:?}", previous_view);
 
+            if !matches!(previous_view, ViewType::Runs | ViewType::PipelineRuns(_)) {
+                self.clear_marks();
+                self.run_statuses.clear();
+            }
+
             // Update to the previous view without modifying history again
             self.view = previous_view;
 
@@ -451,6 +863,9 @@ impl App {
                 ViewType::Pipelines => {
                     PipelinesView::new().restore_state(self).await?;
                 }
+                ViewType::DebugLog => {
+                    DebugLogView::new().restore_state(self).await?;
+                }
                 ViewType::PipelineRuns(_) => {
                     // When going back to pipeline runs, restore with the pipeline filter
                     RunsView::new().restore_state(self).await?;
@@ -501,28 +916,43 @@ impl App {
     pub fn save_view_state(&mut self) {
         // Don't save state for Run view (detail view) or Default view
         if !matches!(&self.view, ViewType::Default) && !matches!(&self.view, ViewType::Run(_)) {
-            self.view_state_cache
-                .insert(self.view.clone(), (self.selected_index, self.list_offset));
+            self.view_state_cache.insert(
+                self.view.clone(),
+                ViewState {
+                    selected_index: self.selected_index,
+                    list_offset: self.list_offset,
+                    sort: self.sort,
+                },
+            );
             log::debug!(
-                "Stored state for {:?}: selected_index={}, list_offset={}",
+                "Stored state for {:?}: selected_index={}, list_offset={}, sort={:?}",
                 self.view,
                 self.selected_index,
-                self.list_offset
+                self.list_offset,
+                self.sort
             );
         }
     }
 
     /// Restore a previously saved view state
     pub fn restore_view_state(&mut self) {
-        if let Some(&(selected_index, list_offset)) = self.view_state_cache.get(&self.view) {
-            self.selected_index = selected_index;
-            self.list_offset = list_offset;
+        if let Some(&view_state) = self.view_state_cache.get(&self.view) {
+            self.selected_index = view_state.selected_index;
+            self.list_offset = view_state.list_offset;
+            // The sorted column may have been removed from `runs_columns` since
+            self.sort = view_state
+                .sort
+                .filter(|sort| self.sort_columns().contains(&sort.column));
             log::debug!(
-                "Restored state for {:?}: selected_index={}, list_offset={}",
+                "Restored state for {:?}: selected_index={}, list_offset={}, sort={:?}",
                 self.view,
-                selected_index,
-                list_offset
+                view_state.selected_index,
+                view_state.list_offset,
+                view_state.sort
             );
+        } else {
+            // Views without saved state start in server order
+            self.sort = None;
         }
     }
 }
@@ -573,37 +1003,37 @@ impl App {
 
     /// Scroll down in the detail view
     fn scroll_down(&mut self) {
-        if let ViewType::Run(_) = self.view {
-            if let Some(run_view) = &mut self.run_view {
-                run_view.scroll_offset = run_view.scroll_offset.saturating_add(1);
-            }
+        if let ViewType::Run(_) = self.view
+            && let Some(run_view) = &mut self.run_view
+        {
+            run_view.scroll_offset = run_view.scroll_offset.saturating_add(1);
         }
     }
 
     /// Scroll up in the detail view
     fn scroll_up(&mut self) {
-        if let ViewType::Run(_) = self.view {
-            if let Some(run_view) = &mut self.run_view {
-                run_view.scroll_offset = run_view.scroll_offset.saturating_sub(1);
-            }
+        if let ViewType::Run(_) = self.view
+            && let Some(run_view) = &mut self.run_view
+        {
+            run_view.scroll_offset = run_view.scroll_offset.saturating_sub(1);
         }
     }
 
     /// Scroll left in the detail view
     fn scroll_left(&mut self) {
-        if let ViewType::Run(_) = self.view {
-            if let Some(run_view) = &mut self.run_view {
-                run_view.horizontal_scroll = run_view.horizontal_scroll.saturating_sub(1);
-            }
+        if let ViewType::Run(_) = self.view
+            && let Some(run_view) = &mut self.run_view
+        {
+            run_view.horizontal_scroll = run_view.horizontal_scroll.saturating_sub(1);
         }
     }
 
     /// Scroll right in the detail view
     fn scroll_right(&mut self) {
-        if let ViewType::Run(_) = self.view {
-            if let Some(run_view) = &mut self.run_view {
-                run_view.horizontal_scroll = run_view.horizontal_scroll.saturating_add(1);
-            }
+        if let ViewType::Run(_) = self.view
+            && let Some(run_view) = &mut self.run_view
+        {
+            run_view.horizontal_scroll = run_view.horizontal_scroll.saturating_add(1);
         }
     }
 }
@@ -622,7 +1052,7 @@ impl App {
         use crate::search::fuzzy_match;
 
         // Keep header and separator (first two rows in most views)
-        let header = self.unfiltered_items.get(0).cloned().unwrap_or_default();
+        let header = self.unfiltered_items.first().cloned().unwrap_or_default();
         let separator = self.unfiltered_items.get(1).cloned().unwrap_or_default();
 
         // Filter the data rows
@@ -703,148 +1133,593 @@ impl App {
     }
 }
 
-// Command Mode
+// Sorting
 
 impl App {
-    /// Toggle command input mode
-    fn toggle_command_mode(&mut self) {
-        self.command_mode = !self.command_mode;
-        if self.command_mode {
-            self.command_input.clear();
-            // Exit search mode if entering command mode
-            self.search_mode = false;
+    /// Sortable columns of the current view, in display order
+    fn sort_columns(&self) -> Vec<SortColumn> {
+        match self.view {
+            ViewType::Runs | ViewType::PipelineRuns(_) => self
+                .config
+                .get_current_context()
+                .runs_columns()
+                .into_iter()
+                .map(SortColumn::Run)
+                .collect(),
+            ViewType::Pipelines => PipelineColumn::ALL
+                .into_iter()
+                .map(SortColumn::Pipeline)
+                .collect(),
+            _ => Vec::new(),
         }
     }
 
-    /// Clear last character from command input
-    fn clear_command_input(&mut self) {
-        self.command_input.pop();
-        if self.command_input.is_empty() {
-            self.command_mode = false;
+    /// Cycle the sort column: server order, then each column in turn, then back to server order
+    async fn cycle_sort_column(&mut self) {
+        let columns = self.sort_columns();
+        let Some(&first) = columns.first() else {
+            return;
+        };
+
+        self.sort = match self.sort {
+            Some(sort) => {
+                let current = columns.iter().position(|&column| column == sort.column);
+                match current.map(|idx| columns.get(idx + 1)) {
+                    Some(Some(&column)) => Some(SortState { column, ..sort }),
+                    Some(None) => None,
+                    // The sorted column isn't shown any more, so start again
+                    None => Some(SortState {
+                        column: first,
+                        ..sort
+                    }),
+                }
+            }
+            None => Some(SortState {
+                column: first,
+                descending: false,
+            }),
+        };
+        log::debug!("Sort changed for {:?}: {:?}", self.view, self.sort);
+
+        self.refresh_sorted_items().await;
+    }
+
+    /// Flip the direction of the active sort
+    async fn toggle_sort_order(&mut self) {
+        if let Some(sort) = &mut self.sort {
+            sort.descending = !sort.descending;
+            log::debug!("Sort changed for {:?}: {:?}", self.view, self.sort);
+            self.refresh_sorted_items().await;
         }
     }
 
-    /// Execute the current command
-    pub async fn execute_command(&mut self) {
-        log::debug!("Executing command: {}", self.command_input);
+    /// Rebuild the current list from the cached data so a new sort takes effect immediately
+    async fn refresh_sorted_items(&mut self) {
+        let has_filter = self.search_mode || self.has_committed_filter;
+
+        match self.view {
+            ViewType::Runs | ViewType::PipelineRuns(_) => {
+                let runs_view = RunsView::new();
+                if has_filter {
+                    let mut unfiltered_items = Vec::new();
+                    runs_view
+                        .populate_runs_items_into(self, &mut unfiltered_items)
+                        .await;
+                    self.unfiltered_items = unfiltered_items;
+                    self.apply_search_filter();
+                } else {
+                    runs_view.populate_runs_items(self).await;
+                }
+            }
+            ViewType::Pipelines => {
+                let pipelines_view = PipelinesView::new();
+                if has_filter {
+                    let mut unfiltered_items = Vec::new();
+                    pipelines_view
+                        .populate_pipelines_items_into(self, &mut unfiltered_items)
+                        .await;
+                    self.unfiltered_items = unfiltered_items;
+                    self.apply_search_filter();
+                } else {
+                    pipelines_view.populate_pipelines_items(self).await;
+                }
+            }
+            _ => {}
+        }
+    }
+}
 
-        if self.command_input.starts_with("url ") {
-            let new_url = self.command_input.trim_start_matches("url ").to_string();
-            log::debug!("Setting new Dagster URL: {}", new_url);
-            self.dagster_url = new_url;
-            self.connection_status = ConnectionStatus::Disconnected;
-        } else if self.command_input.starts_with("context ") {
-            self.execute_context_command().await;
-        } else if self.command_input.starts_with("context-add ") {
-            self.execute_context_add_command();
+// Marking and Bulk Actions
+
+impl App {
+    /// Whether any runs are marked or a visual range is active
+    fn has_marks(&self) -> bool {
+        !self.marked_runs.is_empty() || self.visual_anchor.is_some()
+    }
+
+    /// Drop all marks and leave visual mode
+    fn clear_marks(&mut self) {
+        self.marked_runs.clear();
+        self.visual_anchor = None;
+    }
+
+    /// Whether the row at the given index is inside the active visual range
+    pub fn in_visual_range(&self, index: usize) -> bool {
+        self.visual_anchor.is_some_and(|anchor| {
+            let (start, end) = if anchor <= self.selected_index {
+                (anchor, self.selected_index)
+            } else {
+                (self.selected_index, anchor)
+            };
+            (start..=end).contains(&index)
+        })
+    }
+
+    /// Mark or unmark the selected run
+    fn toggle_mark(&mut self) {
+        if !matches!(self.view, ViewType::Runs | ViewType::PipelineRuns(_)) {
+            return;
+        }
+
+        if let Some(run_id) = self
+            .items
+            .get(self.selected_index)
+            .and_then(|item| row_run_id(item))
+            && !self.marked_runs.remove(run_id)
+        {
+            self.marked_runs.insert(run_id.to_string());
+        }
+    }
+
+    /// Start a visual range at the selected run, or mark the range and leave visual mode
+    fn toggle_visual_mode(&mut self) {
+        if !matches!(self.view, ViewType::Runs | ViewType::PipelineRuns(_)) {
+            return;
+        }
+
+        if self.visual_anchor.is_some() {
+            let range_ids: Vec<String> = self
+                .items
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| self.in_visual_range(i))
+                .filter_map(|(_, item)| row_run_id(item).map(str::to_string))
+                .collect();
+            self.marked_runs.extend(range_ids);
+            self.visual_anchor = None;
+        } else if self.selected_index >= 2 {
+            self.visual_anchor = Some(self.selected_index);
+        }
+    }
+
+    /// Runs that a bulk action applies to: marked runs and the visual range in list order,
+    /// followed by marked runs hidden by the current filter. Falls back to the selected run.
+    pub fn target_run_ids(&self) -> Vec<String> {
+        // The detail view always acts on the run it shows
+        if let ViewType::Run(run_id) = &self.view {
+            return vec![run_id.clone()];
+        }
+
+        let mut run_ids: Vec<String> = self
+            .items
+            .iter()
+            .enumerate()
+            .skip(2)
+            .filter_map(|(i, item)| {
+                let run_id = row_run_id(item)?;
+                (self.marked_runs.contains(run_id) || self.in_visual_range(i))
+                    .then(|| run_id.to_string())
+            })
+            .collect();
+
+        let mut hidden: Vec<String> = self
+            .marked_runs
+            .iter()
+            .filter(|run_id| !run_ids.contains(run_id))
+            .cloned()
+            .collect();
+        hidden.sort();
+        run_ids.extend(hidden);
+
+        if run_ids.is_empty()
+            && let Some(run_id) = self
+                .items
+                .get(self.selected_index)
+                .and_then(|item| row_run_id(item))
+        {
+            run_ids.push(run_id.to_string());
+        }
+
+        run_ids
+    }
+
+    /// Copy the IDs of the targeted runs to the clipboard, one per line
+    fn copy_run_ids(&mut self) {
+        if !matches!(self.view, ViewType::Runs | ViewType::PipelineRuns(_)) {
+            return;
+        }
+
+        let run_ids = self.target_run_ids();
+        if run_ids.is_empty() {
+            return;
+        }
+
+        match copy_to_clipboard(&run_ids.join("\n")) {
+            Ok(()) => {
+                self.status_message = Some(format!("Copied {} run ID(s)", run_ids.len()));
+                self.clear_marks();
+            }
+            Err(e) => {
+                log::error!("Failed to copy run IDs: {}", e);
+                self.status_message = Some(format!("Copy failed: {}", e));
+            }
+        }
+    }
+
+    /// Ask the user to confirm a bulk action on the targeted runs
+    async fn request_bulk_action(&mut self, action: BulkAction) {
+        if !matches!(
+            self.view,
+            ViewType::Runs | ViewType::PipelineRuns(_) | ViewType::Run(_)
+        ) {
+            return;
+        }
+
+        if let Some(refusal) = self.bulk_action_refusal(action) {
+            self.status_message = Some(refusal);
+            return;
+        }
+
+        let run_ids = self.target_run_ids();
+        if run_ids.is_empty() {
+            self.status_message = Some("No runs selected".to_string());
+            return;
+        }
+
+        let summary = {
+            let state = self.state.lock().await;
+            let detail_run = self.run_view.as_ref().and_then(|v| v.details.as_ref());
+            run_ids
+                .iter()
+                .map(|run_id| {
+                    match state
+                        .runs
+                        .iter()
+                        .chain(detail_run)
+                        .find(|r| &r.run_id == run_id)
+                    {
+                        Some(run) => format!("{}  {}  {}", run.run_id, run.job_name, run.status),
+                        None => run_id.clone(),
+                    }
+                })
+                .collect()
+        };
+
+        self.confirmation = Some(Confirmation {
+            action,
+            run_ids,
+            summary,
+            typed: action == BulkAction::Delete,
+            input: String::new(),
+        });
+    }
+
+    /// Why the current context doesn't allow `action`, if it doesn't
+    fn bulk_action_refusal(&self, action: BulkAction) -> Option<String> {
+        if self.context_mode() == ContextMode::ReadOnly {
+            Some(format!(
+                "Context '{}' is read-only",
+                self.config.last_context
+            ))
+        } else if action == BulkAction::Delete
+            && !self.config.get_current_context().allow_destructive
+        {
+            Some(format!(
+                "Deleting runs is disabled for context '{}' (set allow_destructive = true)",
+                self.config.last_context
+            ))
         } else {
-            self.execute_standard_command().await;
+            None
         }
+    }
 
-        self.command_mode = false;
-        self.command_input.clear();
+    /// Handle a character typed while a confirmation is showing. Single-key prompts
+    /// accept 'y' and treat anything else as a cancel.
+    fn update_confirm_input(&mut self, c: char) {
+        let Some(confirmation) = &mut self.confirmation else {
+            return;
+        };
+
+        if confirmation.typed {
+            confirmation.input.push(c);
+        } else if c == 'y' || c == 'Y' {
+            self.confirm_action();
+        } else {
+            self.cancel_confirmation();
+        }
     }
 
-    /// Execute a context switching command
-    async fn execute_context_command(&mut self) {
-        let context_name = self
-            .command_input
-            .trim_start_matches("context ")
-            .to_string();
-        if let Err(e) = self.config.set_context(&context_name) {
-            log::error!("Failed to switch context: {}", e);
-            self.connection_status = ConnectionStatus::Failed(format!("Context error: {}", e));
+    /// Handle Enter while a confirmation is showing
+    fn submit_confirmation(&mut self) {
+        let Some(confirmation) = &mut self.confirmation else {
+            return;
+        };
+
+        if !confirmation.typed {
+            // Enter is not a yes for single-key prompts
+            self.cancel_confirmation();
+        } else if confirmation.is_confirmed_by_input() {
+            self.confirm_action();
         } else {
-            // Update the URL from the new context
-            let context_config = self.config.get_current_context();
-            self.dagster_url = context_config.url;
-            self.connection_status = ConnectionStatus::Disconnected;
-            log::debug!("Switched to context: {}", context_name);
+            // Keep the prompt open so the user can fix a typo
+            confirmation.input.clear();
+            self.status_message = Some("Confirmation text did not match".to_string());
         }
     }
 
-    /// Execute a context add command
-    fn execute_context_add_command(&mut self) {
-        // Parse context-add command: "context-add name url [runs_limit]"
-        let args = self
-            .command_input
-            .trim_start_matches("context-add ")
-            .split_whitespace()
-            .collect::<Vec<&str>>();
+    /// Dismiss the pending confirmation without doing anything
+    fn cancel_confirmation(&mut self) {
+        if let Some(confirmation) = self.confirmation.take() {
+            log::debug!("Cancelled {:?}", confirmation.action);
+        }
+    }
 
-        if args.len() < 2 {
-            log::error!(
-                "Invalid context-add command. Format: context-add <name> <url> [<runs_limit>]"
-            );
-            self.connection_status = ConnectionStatus::Failed(
-                "Invalid context-add command. Format: context-add <name> <url> [<runs_limit>]"
-                    .to_string(),
-            );
+    /// Queue the confirmed mutation for every targeted run. It's sent by
+    /// `App::run_bulk_action` once the app lock is released, so the UI keeps running meanwhile.
+    fn confirm_action(&mut self) {
+        let Some(confirmation) = self.confirmation.take() else {
+            return;
+        };
+
+        // The context may have become read-only (e.g. a config reload) while the prompt was open
+        if let Some(refusal) = self.bulk_action_refusal(confirmation.action) {
+            log::warn!("Not applying {:?}: {}", confirmation.action, refusal);
+            self.status_message = Some(refusal);
             return;
         }
 
-        let name = args[0];
-        let url = args[1];
-        let runs_limit = if args.len() > 2 {
-            match args[2].parse::<usize>() {
-                Ok(limit) => Some(limit),
-                Err(_) => {
-                    log::warn!("Invalid runs_limit value: {}, using default", args[2]);
-                    None
+        self.status_message = Some(format!(
+            "{}: sending {} run(s)...",
+            confirmation.action.verb(),
+            confirmation.run_ids.len()
+        ));
+        self.clear_marks();
+        self.pending_bulk_action = Some(PendingBulkAction {
+            action: confirmation.action,
+            run_ids: confirmation.run_ids,
+            client: self.client.clone(),
+        });
+    }
+
+    /// Send a confirmed bulk action, a few runs at a time, then report the outcome
+    pub async fn run_bulk_action(app: Arc<Mutex<App>>, pending: PendingBulkAction) {
+        let PendingBulkAction {
+            action,
+            run_ids,
+            client,
+        } = pending;
+        let total = run_ids.len();
+
+        let mut queued = run_ids.clone().into_iter();
+        let mut in_flight = JoinSet::new();
+        let mut results = Vec::with_capacity(total);
+        loop {
+            while in_flight.len() < MAX_CONCURRENT_MUTATIONS
+                && let Some(run_id) = queued.next()
+            {
+                let client = client.clone();
+                in_flight.spawn(async move {
+                    let result = action.apply(&client, run_id.clone()).await;
+                    (run_id, result)
+                });
+            }
+            match in_flight.join_next().await {
+                Some(Ok(result)) => results.push(result),
+                Some(Err(e)) => log::error!("{} task failed: {}", action.verb(), e),
+                None => break,
+            }
+        }
+        // Report in the order the runs were listed rather than the order they finished
+        results.sort_by_key(|(run_id, _)| run_ids.iter().position(|id| id == run_id));
+
+        // A run whose task panicked has no result, so it isn't counted as ok
+        let succeeded = results.iter().filter(|(_, result)| result.is_ok()).count();
+
+        let mut app = app.lock().await;
+        let mut errors = Vec::new();
+        for (run_id, result) in results {
+            match result {
+                Ok(affected_run_id) => {
+                    log::info!("{} {}: ok ({})", action.verb(), run_id, affected_run_id)
+                }
+                Err(e) => {
+                    log::error!("{} {} failed: {}", action.verb(), run_id, e);
+                    if errors.is_empty() {
+                        app.last_error = Some(e.clone());
+                    }
+                    errors.push(format!("{}: {}", run_id, e));
                 }
             }
+        }
+
+        app.status_message = Some(match errors.first() {
+            None => format!("{}: {}/{} run(s) ok", action.verb(), succeeded, total),
+            Some(first_error) => format!(
+                "{}: {}/{} run(s) ok, first error: {} (e for details)",
+                action.verb(),
+                succeeded,
+                total,
+                first_error
+            ),
+        });
+
+        // Show the runs' new statuses without waiting for the next poll
+        app.request_refresh();
+
+        // A deleted run can't be shown anymore, so leave its detail view
+        if action == BulkAction::Delete
+            && errors.is_empty()
+            && matches!(&app.view, ViewType::Run(run_id) if run_ids.contains(run_id))
+            && let Err(e) = app.navigate_back().await
+        {
+            log::error!("Error navigating back: {:?}", e);
+        }
+    }
+}
+
+// Command Mode
+
+impl App {
+    /// Toggle command input mode
+    fn toggle_command_mode(&mut self) {
+        self.command_mode = !self.command_mode;
+        if self.command_mode {
+            self.command_line.clear();
+            // Exit search mode if entering command mode
+            self.search_mode = false;
+        }
+    }
+
+    /// Apply an edit to the command line; Backspace on an empty line leaves command mode
+    fn edit_command(&mut self, edit: CommandEdit) {
+        if edit == CommandEdit::Backspace && self.command_line.input().is_empty() {
+            self.command_mode = false;
         } else {
-            None
+            self.command_line.edit(edit);
+        }
+    }
+
+    /// Tab-complete the word before the cursor: a command, alias, context, job or run ID
+    async fn complete_command(&mut self) {
+        let (words, _) = self.command_line.completion_context();
+        // Complete arguments of an alias like those of the command it stands for
+        let words: Vec<String> = if words.is_empty() {
+            Vec::new()
+        } else {
+            aliases::expand(&self.config.aliases, &words.join(" "))
+                .unwrap_or_default()
+                .split_whitespace()
+                .map(str::to_string)
+                .collect()
         };
 
-        // Create a new context configuration
-        let context_config = ContextConfig {
-            url: url.to_string(),
-            runs_limit,
+        let candidates: Vec<String> = match words.first().map(String::as_str) {
+            None => COMMANDS
+                .iter()
+                .filter_map(|(usage, _)| usage.split_whitespace().next())
+                .map(str::to_string)
+                .chain(
+                    aliases::all(&self.config.aliases)
+                        .into_iter()
+                        .map(|(alias, _)| alias),
+                )
+                .collect(),
+            Some("context") if words.len() == 1 => self.config.contexts.keys().cloned().collect(),
+            Some("view" | "view-delete") if words.len() == 1 => {
+                self.config.views.keys().cloned().collect()
+            }
+            Some("runs") => {
+                let state = self.state.lock().await;
+                state
+                    .pipelines
+                    .iter()
+                    .map(|pipeline| &pipeline.name)
+                    .chain(state.runs.iter().map(|run| &run.job_name))
+                    .cloned()
+                    .chain(RUN_STATUS_WORDS.iter().map(|word| word.to_string()))
+                    .collect()
+            }
+            Some("run") if words.len() == 1 => {
+                let state = self.state.lock().await;
+                state.runs.iter().map(|run| run.run_id.clone()).collect()
+            }
+            _ => Vec::new(),
         };
+        self.command_line.complete(&candidates);
+    }
 
-        // Add the new context
-        if let Err(e) = self.config.add_context(name, context_config) {
-            log::error!("Failed to add context: {}", e);
-            self.connection_status = ConnectionStatus::Failed(format!("Context error: {}", e));
-        } else {
-            log::debug!("Added new context: {}", name);
+    /// Execute the current command, after expanding any alias
+    ///
+    /// Commands that can't run keep the command line open with the reason shown inline.
+    pub async fn execute_command(&mut self) {
+        let input = self.command_line.input().trim().to_string();
+        if input.is_empty() {
+            self.command_mode = false;
+            return;
+        }
 
-            // Refresh the contexts list if we're on the Contexts view
-            if let ViewType::Contexts = self.view {
-                self.populate_contexts_list();
+        self.command_line.record();
+        let result = match aliases::expand(&self.config.aliases, &input) {
+            Ok(command) => {
+                log::debug!("Executing command: {}", command);
+                let (name, args) = aliases::split_command(&command);
+                self.run_command(name, args).await
             }
+            Err(e) => Err(e),
+        };
 
-            self.connection_status = ConnectionStatus::Connected;
+        match result {
+            Ok(()) => {
+                self.command_mode = false;
+                self.command_line.clear();
+            }
+            Err(e) => {
+                log::debug!("Command failed: {}: {}", input, e);
+                self.command_line.error = Some(e);
+            }
         }
     }
 
-    /// Execute a standard command (run, pipelines, etc.)
-    /// Make sure nav history is reset when explicilty navigating via command.
-    async fn execute_standard_command(&mut self) {
-        match self.command_input.as_str() {
-            "contexts" => {
+    /// Run a command, or explain why it can't run
+    /// Make sure nav history is reset when explicitly navigating via command.
+    async fn run_command(&mut self, name: &str, args: &str) -> Result<(), String> {
+        let Some((usage, _)) = COMMANDS
+            .iter()
+            .find(|(usage, _)| usage.split_whitespace().next() == Some(name))
+        else {
+            return Err(format!("Unknown command: {}", name));
+        };
+        let usage_error = || format!("Usage: :{}", usage);
+
+        match (name, args) {
+            ("url", "")
+            | ("context", "")
+            | ("context-add", "")
+            | ("run", "")
+            | ("save", "")
+            | ("view", "")
+            | ("view-delete", "") => {
+                return Err(usage_error());
+            }
+            ("save" | "view" | "view-delete", name) if name.contains(char::is_whitespace) => {
+                return Err(usage_error());
+            }
+            ("url", url) => self.set_url(url)?,
+            ("context", context_name) => self.execute_context_command(context_name)?,
+            ("context-add", args) => self.execute_context_add_command(args)?,
+            ("run", run_id) => self.open_run(run_id.to_string()).await,
+            ("runs", filters) => self.execute_runs_command(filters).await?,
+            ("save", name) => self.save_current_view(name)?,
+            ("view", name) => self.open_saved_view(name).await?,
+            ("view-delete", name) => {
+                self.config
+                    .remove_view(name)
+                    .map_err(|e| format!("Config error: {}", e))?;
+                self.status_message = Some(format!("Deleted saved view '{}'", name));
+            }
+            (_, args) if !args.is_empty() => return Err(usage_error()),
+            ("contexts", _) => {
                 if let Err(e) = self.enter_view(ViewType::Contexts, true).await {
                     log::error!("Failed to switch to contexts view: {:?}", e);
                 }
             }
-            "runs" => {
-                log::debug!("Switching to runs view");
-                {
-                    let mut state = self.state.lock().await;
-                    state.selected_pipeline = None;
-                }
-                if let Err(e) = self.enter_view(ViewType::Runs, true).await {
-                    log::error!("Failed to switch to runs view: {:?}", e);
-                }
-            }
-            "pipelines" => {
+            ("pipelines", _) => {
                 log::debug!("Switching to pipelines view");
                 if let Err(e) = self.enter_view(ViewType::Pipelines, true).await {
                     log::error!("Failed to switch to pipelines view: {:?}", e);
                 }
             }
-            "debug" => {
+            ("debug", _) => {
                 let state = self.state.lock().await;
                 log::info!("App Debug Info:");
                 log::info!("View: {:?}", self.view);
@@ -853,14 +1728,366 @@ impl App {
                 log::info!("State pipelines count: {}", state.pipelines.len());
                 log::info!("History depth: {}", self.view_history.len());
             }
-            "q" => std::process::exit(0),
-            _ => {
-                log::debug!("Unknown command: {}", self.command_input);
+            ("aliases", _) => {
+                self.help = Some(HelpPage::Aliases);
+                self.help_scroll = 0;
+            }
+            ("views", _) => {
+                self.help = Some(HelpPage::Views);
+                self.help_scroll = 0;
+            }
+            ("debuglog", _) => {
+                if let Err(e) = self.enter_view(ViewType::DebugLog, false).await {
+                    log::error!("Failed to switch to debug log view: {:?}", e);
+                }
+            }
+            ("q", _) => self.quit_requested = true,
+            _ => return Err(format!("Unknown command: {}", name)),
+        }
+        Ok(())
+    }
+
+    /// Show the runs list, narrowed by `job:<job>`, statuses such as `failed`, and filter text
+    async fn execute_runs_command(&mut self, filters: &str) -> Result<(), String> {
+        let mut job = None;
+        let mut statuses = Vec::new();
+        for term in filters.split_whitespace() {
+            if let Some(status) = term.strip_prefix("status:") {
+                let matching =
+                    run_statuses(status).ok_or_else(|| format!("Unknown status: {}", status))?;
+                statuses.extend(matching.iter().map(|status| status.to_string()));
+            } else if let (None, Some(matching)) = (term.strip_prefix("job:"), run_statuses(term)) {
+                statuses.extend(matching.iter().map(|status| status.to_string()));
+            } else {
+                // A bare word that isn't a status is the job, as in `:runs <job>`
+                let name = term.strip_prefix("job:").unwrap_or(term);
+                if job.replace(name.to_string()).is_some() {
+                    return Err("Usage: :runs [<job>] [<status>...]".to_string());
+                }
+            }
+        }
+
+        self.show_runs(job, statuses).await;
+        Ok(())
+    }
+
+    /// Show the runs of `job`, or of every job, limited to `statuses` if any are given
+    async fn show_runs(&mut self, job: Option<String>, statuses: Vec<String>) {
+        log::debug!(
+            "Switching to runs of {:?} with statuses {:?}",
+            job,
+            statuses
+        );
+        let view = match &job {
+            Some(job) => ViewType::PipelineRuns(job.clone()),
+            None => ViewType::Runs,
+        };
+        {
+            let mut state = self.state.lock().await;
+            state.selected_pipeline = job;
+        }
+
+        if self.view == view {
+            // Already showing this list, so just replace its filters
+            self.run_statuses = statuses;
+            self.cancel_search();
+            if let Err(e) = RunsView::new().fetch_initial_data(self).await {
+                self.report_error(e);
+            }
+        } else if let Err(e) = self.enter_view_with_statuses(view, true, statuses).await {
+            log::error!("Failed to switch to runs view: {:?}", e);
+        }
+    }
+
+    /// Save the current view, with its context, status filters, sort and search, as `name`
+    fn save_current_view(&mut self, name: &str) -> Result<(), String> {
+        if self.view == ViewType::Default {
+            return Err("Open a view to save it first".to_string());
+        }
+
+        let view = self.current_view();
+        self.config
+            .save_view(name, view)
+            .map_err(|e| format!("Config error: {}", e))?;
+        log::debug!("Saved view {}: {:?}", name, self.config.views[name]);
+        self.status_message = Some(format!("Saved view '{}'", name));
+        Ok(())
+    }
+
+    /// The current view with its context, status filters, sort and search
+    fn current_view(&self) -> SavedView {
+        let search = if self.search_mode || self.has_committed_filter {
+            self.search_input.clone()
+        } else {
+            String::new()
+        };
+        SavedView {
+            context: self.config.last_context.clone(),
+            view: self.view.clone(),
+            statuses: self.run_statuses.clone(),
+            search,
+            sort: self.sort,
+        }
+    }
+
+    /// Open a saved view by name
+    async fn open_saved_view(&mut self, name: &str) -> Result<(), String> {
+        let saved = self
+            .config
+            .views
+            .get(name)
+            .cloned()
+            .ok_or_else(|| format!("No saved view named '{}'", name))?;
+        log::debug!("Opening saved view {}: {:?}", name, saved);
+        self.open_view(saved).await
+    }
+
+    /// Open a view, switching to its context and reapplying its filters and sort
+    pub async fn open_view(&mut self, saved: SavedView) -> Result<(), String> {
+        if saved.context != self.config.last_context {
+            self.execute_context_command(&saved.context)?;
+            // Lists already on screen belong to the old context
+            self.request_refresh();
+        }
+
+        match saved.view {
+            ViewType::Runs => self.show_runs(None, saved.statuses).await,
+            ViewType::PipelineRuns(job) => self.show_runs(Some(job), saved.statuses).await,
+            view => self.open_start_view(view).await,
+        }
+
+        // Columns depend on the context, so skip sorts that no longer fit
+        if let Some(sort) = saved.sort
+            && self.sort_columns().contains(&sort.column)
+        {
+            self.sort = Some(sort);
+            self.refresh_sorted_items().await;
+        }
+        if !saved.search.is_empty() && self.view.is_searchable() {
+            self.cancel_search();
+            self.search_input = saved.search;
+            self.unfiltered_items = self.items.clone();
+            self.apply_search_filter();
+            self.has_committed_filter = true;
+        }
+        Ok(())
+    }
+
+    /// Execute a context switching command
+    fn execute_context_command(&mut self, context_name: &str) -> Result<(), String> {
+        self.save_session();
+        self.config
+            .set_context(context_name)
+            .map_err(|e| format!("Context error: {}", e))?;
+
+        self.enter_current_context();
+        log::debug!("Switched to context: {}", context_name);
+        Ok(())
+    }
+
+    /// Connect to the context `last_context` now names, with the list positions saved for it
+    fn enter_current_context(&mut self) {
+        self.connection_status = ConnectionStatus::Disconnected;
+        self.connect_current_context();
+
+        // Positions and sorts belong to the previous context's lists
+        self.view_state_cache.clear();
+        self.restore_session();
+    }
+
+    /// Build the client for the current context, resolving its auth and TLS settings
+    ///
+    /// Secrets may come from a command or the environment, so this only runs
+    /// when the context changes rather than on every poll.
+    fn connect_current_context(&mut self) {
+        let context = self.config.get_current_context();
+        match DagsterClient::for_context(&context) {
+            Ok(client) => self.client = client,
+            Err(e) => {
+                log::error!("Failed to set up {}: {}", self.config.last_context, e);
+                let error = format!(
+                    "Failed to set up context '{}': {}",
+                    self.config.last_context, e
+                );
+                self.client = DagsterClient::failed(&context, error.clone());
+                self.report_error(DagsterError::Config(error));
+            }
+        }
+    }
+
+    /// Point the current context's client at another URL until the context changes
+    pub fn set_url(&mut self, url: &str) -> Result<(), String> {
+        log::debug!("Setting new Dagster URL: {}", url);
+        let context = self.config.get_current_context().with_url(url)?;
+        self.client = DagsterClient::for_context(&context)
+            .map_err(|e| format!("Failed to set up {}: {}", url, e))?;
+        self.connection_status = ConnectionStatus::Disconnected;
+        Ok(())
+    }
+
+    /// Execute a context add command: "context-add name url [runs_limit]"
+    fn execute_context_add_command(&mut self, args: &str) -> Result<(), String> {
+        let (name, url, runs_limit) = match args.split_whitespace().collect::<Vec<_>>()[..] {
+            [name, url] => (name, url, None),
+            [name, url, runs_limit] => (
+                name,
+                url,
+                Some(
+                    runs_limit
+                        .parse::<usize>()
+                        .map_err(|_| format!("Invalid runs_limit: {}", runs_limit))?,
+                ),
+            ),
+            _ => return Err("Usage: :context-add <name> <url> [<runs_limit>]".to_string()),
+        };
+
+        // Create a new context configuration
+        let context_config = ContextConfig {
+            url: url.to_string(),
+            runs_limit,
+            ..ContextConfig::default()
+        };
+
+        // Add the new context
+        self.config
+            .add_context(name, context_config)
+            .map_err(|e| format!("Context error: {}", e))?;
+        log::debug!("Added new context: {}", name);
+
+        // Refresh the contexts list if we're on the Contexts view
+        if let ViewType::Contexts = self.view {
+            self.populate_contexts_list();
+        }
+
+        self.connection_status = ConnectionStatus::Connected;
+        Ok(())
+    }
+}
+
+// Config Reloading
+
+impl App {
+    /// Apply the config file if it was edited since it was last loaded or saved
+    ///
+    /// An invalid file is reported and the current config kept, so a half-finished
+    /// edit doesn't take anything away; saving is refused until it's fixed.
+    pub fn reload_config_if_changed(&mut self) {
+        let modified = self.config.modified_on_disk();
+        if modified.is_none() || modified == self.config_checked {
+            return;
+        }
+        self.config_checked = modified;
+        if modified == self.config.modified() {
+            // Written by d9s itself
+            return;
+        }
+
+        match self.config.reload() {
+            Ok(config) => {
+                log::info!("Reloaded config");
+                self.apply_config(config);
+                self.status_message = Some("Reloaded config".to_string());
+            }
+            Err(e) => {
+                log::warn!("Not reloading config: {}", e);
+                self.last_error = Some(DagsterError::Config(e.to_string()));
+                self.status_message =
+                    Some("Config has errors and wasn't reloaded; see the error panel".to_string());
             }
         }
     }
+
+    /// Switch to a freshly loaded config: its contexts, theme, key bindings and aliases
+    fn apply_config(&mut self, config: Config) {
+        let reconnect = config.last_context != self.config.last_context
+            || config.get_current_context() != self.config.get_current_context();
+
+        // Both were validated when the config was loaded
+        self.theme = config.theme.resolve().unwrap_or_default();
+        self.keymap = Keymap::new(&config.keys).unwrap_or_default();
+        self.config = config;
+
+        if reconnect {
+            self.connection_status = ConnectionStatus::Disconnected;
+            self.connect_current_context();
+            self.request_refresh();
+        }
+        match self.view {
+            ViewType::Default => DefaultView::populate_help_text(self),
+            ViewType::Contexts => self.populate_contexts_list(),
+            _ => {}
+        }
+    }
+}
+
+// Session
+
+impl App {
+    /// Remember the current view and list positions for the current context, and save them
+    pub fn save_session(&mut self) {
+        if self.session.is_none() {
+            return;
+        }
+
+        self.save_view_state();
+        let context_session = ContextSession {
+            view: self.current_view(),
+            view_states: self
+                .view_state_cache
+                .iter()
+                .map(|(view, state)| (view.clone(), *state))
+                .collect(),
+        };
+        let context = self.config.last_context.clone();
+        if let Some(session) = &mut self.session {
+            session.contexts.insert(context, context_session);
+            session.save();
+        }
+    }
+
+    /// Restore the list positions saved for the current context, returning its last view
+    pub fn restore_session(&mut self) -> Option<SavedView> {
+        let context_session = self
+            .session
+            .as_ref()?
+            .contexts
+            .get(&self.config.last_context)?
+            .clone();
+        log::debug!("Restoring session: {:?}", context_session);
+
+        self.view_state_cache.extend(context_session.view_states);
+        Some(context_session.view)
+    }
 }
 
+/// Status words `:runs` accepts, offered by tab completion
+const RUN_STATUS_WORDS: &[&str] = &["failed", "success", "running", "queued", "canceled"];
+
+/// Commands accepted in command mode, as (usage, description), for the help screens
+pub const COMMANDS: &[(&str, &str)] = &[
+    (
+        "runs [<job>] [<status>...]",
+        "Show pipeline runs, optionally of one job and with some statuses",
+    ),
+    ("run <run_id>", "Show the details of a run"),
+    ("pipelines", "Show available pipelines"),
+    ("contexts", "Manage connection contexts"),
+    ("url <url>", "Set Dagster GraphQL URL"),
+    ("context <name>", "Switch to a different context"),
+    ("context-add <name> <url> [<runs_limit>]", "Add a context"),
+    ("aliases", "List command aliases"),
+    (
+        "save <name>",
+        "Save the current view with its filters and sort",
+    ),
+    ("view <name>", "Open a saved view"),
+    ("views", "List saved views"),
+    ("view-delete <name>", "Delete a saved view"),
+    ("debug", "Log application debug information"),
+    ("debuglog", "Show recent log records and request timings"),
+    ("q", "Quit application"),
+];
+
 // Context Management
 
 impl App {
@@ -868,7 +2095,8 @@ impl App {
     pub fn populate_contexts_list(&mut self) {
         self.items.clear();
         self.items.push(
-            "CONTEXT NAME     URL                                      RUNS LIMIT".to_string(),
+            "CONTEXT NAME     URL                                      RUNS LIMIT  MODE"
+                .to_string(),
         );
         self.items.push("-".repeat(80));
 
@@ -883,9 +2111,16 @@ impl App {
             let name_col = format!("{}{:<15}", prefix, name);
             let url_col = format!("{:<40}", context.url);
             let limit_col = format!("{:<10}", limit);
+            let mode_col = if context.production {
+                format!("{} (production)", context.mode.label())
+            } else {
+                context.mode.label().to_string()
+            };
 
-            self.items
-                .push(format!("{} {} {}", name_col, url_col, limit_col));
+            self.items.push(format!(
+                "{} {} {} {}",
+                name_col, url_col, limit_col, mode_col
+            ));
         }
 
         self.selected_index = 2; // Point to first context after header
@@ -894,72 +2129,89 @@ impl App {
 
     /// Enter a specific run details
     async fn enter_run_details_view(&mut self) {
-        if matches!(self.view, ViewType::Runs | ViewType::PipelineRuns(_)){
-            if self.selected_index >= 2 {
-                // Save current view state before switching
-                self.save_view_state();
-
-                if let Some(selected_item) = self.items.get(self.selected_index) {
-                    let run_id = selected_item
-                        .split_whitespace()
-                        .next()
-                        .unwrap_or_default()
-                        .to_string();
-                    let mut run_view = RunView::new(run_id.clone());
-                    let dagster_url = self.dagster_url.clone();
-
-                    // Perform initial fetch
-                    if let Err(e) = run_view.fetch_details(&dagster_url).await {
-                        log::error!("Failed to fetch initial run details: {}", e);
-                        self.connection_status = ConnectionStatus::Failed(e.to_string());
-                    } else {
-                        self.connection_status = ConnectionStatus::Connected;
-                    }
+        if matches!(self.view, ViewType::Runs | ViewType::PipelineRuns(_))
+            && self.selected_index >= 2
+        {
+            // Save current view state before switching
+            self.save_view_state();
+
+            if let Some(selected_item) = self.items.get(self.selected_index) {
+                // Run rows start with the run ID, whichever columns are displayed
+                let Some(run_id) = row_run_id(selected_item).map(str::to_string) else {
+                    return;
+                };
+                self.open_run(run_id).await;
+            }
+        }
+    }
 
-                    // Save the run view (we'll need it later)
-                    self.run_view = Some(run_view);
+    /// Fetch a run and show its details on top of the current view
+    async fn open_run(&mut self, run_id: String) {
+        let mut run_view = RunView::new(run_id.clone());
 
-                    // Use enter_view with reset_history=false to preserve navigation history
-                    if let Err(e) = self.enter_view(ViewType::Run(run_id), false).await {
-                        log::error!("Failed to enter run details view: {:?}", e);
-                    }
-                }
+        // Perform initial fetch
+        if let Err(e) = run_view.fetch_details(&self.client).await {
+            log::error!("Failed to fetch initial run details: {}", e);
+            self.report_error(e);
+        } else {
+            self.connection_status = ConnectionStatus::Connected;
+        }
+
+        // Save the run view (we'll need it later)
+        self.run_view = Some(run_view);
+
+        // Use enter_view with reset_history=false to preserve navigation history
+        if let Err(e) = self.enter_view(ViewType::Run(run_id), false).await {
+            log::error!("Failed to enter run details view: {:?}", e);
+        }
+    }
+
+    /// Open the view requested on the command line
+    pub async fn open_start_view(&mut self, view: ViewType) {
+        let result = match view {
+            // Going back from a run opened directly lands on the runs list
+            ViewType::Run(run_id) => {
+                let result = self.enter_view(ViewType::Runs, true).await;
+                self.open_run(run_id).await;
+                result
             }
+            view => self.enter_view(view, true).await,
+        };
+
+        if let Err(e) = result {
+            log::error!("Failed to open start view: {:?}", e);
         }
     }
 
     /// Enter the pipeline runs view
     async fn enter_pipeline_runs_view(&mut self) {
-        if let ViewType::Pipelines = self.view {
-            if self.selected_index >= 2 {
-                // Save current view state before switching
-                self.save_view_state();
-
-                if let Some(selected_item) = self.items.get(self.selected_index) {
-                    // Extract pipeline name from selected item (first column)
-                    // The pipeline name could contain spaces, so we need to be careful
-                    // We know that we format it as a fixed-width column of 30 chars
-                    let pipeline_name = selected_item
-                        .trim()
-                        .chars()
-                        .take(30)
-                        .collect::<String>()
-                        .trim()
-                        .to_string();
-
-                    // Store selected pipeline in state
-                    {
-                        let mut state = self.state.lock().await;
-                        state.selected_pipeline = Some(pipeline_name.clone());
-                    }
+        if let ViewType::Pipelines = self.view
+            && self.selected_index >= 2
+        {
+            // Save current view state before switching
+            self.save_view_state();
+
+            if let Some(selected_item) = self.items.get(self.selected_index) {
+                // Extract pipeline name from selected item (first column).
+                // Dagster job names can't contain whitespace, so the first word is the name.
+                let pipeline_name = selected_item
+                    .split_whitespace()
+                    .next()
+                    .unwrap_or_default()
+                    .to_string();
+
+                // Store selected pipeline in state
+                {
+                    let mut state = self.state.lock().await;
+                    state.selected_pipeline = Some(pipeline_name.clone());
+                }
 
-                    // Navigate to the PipelineRuns view for this pipeline
-                    if let Err(e) = self
-                        .enter_view(ViewType::PipelineRuns(pipeline_name), false)
-                        .await
-                    {
-                        log::error!("Failed to enter pipeline runs view: {:?}", e);
-                    }
+                // Navigate to the PipelineRuns view for this pipeline
+                if let Err(e) = self
+                    .enter_view(ViewType::PipelineRuns(pipeline_name), false)
+                    .await
+                {
+                    log::error!("Failed to enter pipeline runs view: {:?}", e);
                 }
             }
         }
@@ -971,8 +2223,8 @@ impl App {
             let name = if context_name.is_empty() {
                 // If no name provided, extract from selected item
                 if let Some(item) = self.items.get(self.selected_index) {
-                    let parts: Vec<&str> = item.trim().split_whitespace().collect();
-                    if let Some(name) = parts.get(0) {
+                    let parts: Vec<&str> = item.split_whitespace().collect();
+                    if let Some(name) = parts.first() {
                         name.trim_start_matches('*').trim().to_string()
                     } else {
                         return;
@@ -984,12 +2236,9 @@ impl App {
                 context_name
             };
 
-            if let Err(e) = self.config.set_context(&name) {
-                self.connection_status = ConnectionStatus::Failed(format!("Context error: {}", e));
+            if let Err(e) = self.execute_context_command(&name) {
+                self.connection_status = ConnectionStatus::Failed(e);
             } else {
-                let context = self.config.get_current_context();
-                self.dagster_url = context.url;
-                self.connection_status = ConnectionStatus::Disconnected;
                 self.populate_contexts_list();
             }
         }
@@ -999,24 +2248,178 @@ impl App {
     fn add_context(&mut self) {
         // For now, just enter command mode with a template
         self.command_mode = true;
-        self.command_input = "context-add name url".to_string();
+        self.command_line.set("context-add ");
     }
 
     /// Delete the selected context
     fn delete_context(&mut self) {
-        if let ViewType::Contexts = self.view {
-            if let Some(item) = self.items.get(self.selected_index) {
-                let parts: Vec<&str> = item.trim().split_whitespace().collect();
-                if let Some(name) = parts.get(0) {
-                    let name = name.trim_start_matches('*').trim();
-                    if let Err(e) = self.config.remove_context(name) {
-                        self.connection_status =
-                            ConnectionStatus::Failed(format!("Cannot delete: {}", e));
-                    } else {
-                        self.populate_contexts_list();
+        if let ViewType::Contexts = self.view
+            && let Some(item) = self.items.get(self.selected_index)
+        {
+            let parts: Vec<&str> = item.split_whitespace().collect();
+            if let Some(name) = parts.first() {
+                let name = name.trim_start_matches('*').trim();
+                let current = self.config.last_context.clone();
+                if let Err(e) = self.config.remove_context(name) {
+                    self.connection_status =
+                        ConnectionStatus::Failed(format!("Cannot delete: {}", e));
+                } else {
+                    // Deleting the current context falls back to the default one
+                    if self.config.last_context != current {
+                        self.enter_current_context();
                     }
+                    self.populate_contexts_list();
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_calculate_widths() {
+        let columns = ColumnsConfig::new(&RunColumn::DEFAULT);
+
+        // Plenty of room: every column gets at least its desired width, in display order
+        let widths = columns.calculate_widths(200);
+        assert_eq!(widths.len(), 5);
+        for (col, width) in columns.columns.iter().zip(&widths) {
+            assert!(*width >= col.width);
+        }
+
+        // Narrow: the lowest priority column (START TIME) is hidden first
+        let widths = columns.calculate_widths(70);
+        assert_eq!(widths[4], 0);
+        assert_eq!(widths[0], 36);
+    }
+
+    #[test]
+    fn test_typed_confirmation() {
+        let mut confirmation = Confirmation {
+            action: BulkAction::Delete,
+            run_ids: vec!["abc-123".to_string()],
+            summary: vec![],
+            typed: true,
+            input: String::new(),
+        };
+        assert!(!confirmation.is_confirmed_by_input());
+
+        confirmation.input = "abc-123".to_string();
+        assert!(confirmation.is_confirmed_by_input());
+        confirmation.input = "yes".to_string();
+        assert!(confirmation.is_confirmed_by_input());
+        confirmation.input = "y".to_string();
+        assert!(!confirmation.is_confirmed_by_input());
+
+        // With several runs only "yes" is accepted
+        confirmation.run_ids.push("def-456".to_string());
+        confirmation.input = "abc-123".to_string();
+        assert!(!confirmation.is_confirmed_by_input());
+    }
+
+    #[tokio::test]
+    async fn test_confirmation_rechecks_context_mode() {
+        let mut app = App::new(Config::default(), None);
+        app.view = ViewType::Runs;
+        app.confirmation = Some(Confirmation {
+            action: BulkAction::Terminate,
+            run_ids: vec!["abc-123".to_string()],
+            summary: vec![],
+            typed: false,
+            input: String::new(),
+        });
+
+        // Switching to read-only while the prompt is open must stop the action
+        app.force_read_only = true;
+        app.apply_key_action(KeyAction::UpdateConfirmInput('y'), 10)
+            .await
+            .unwrap();
+        assert!(app.confirmation.is_none());
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("Context 'default' is read-only")
+        );
+    }
+
+    #[test]
+    fn test_restored_sort_needs_a_shown_column() {
+        let mut app = App::new(Config::default(), None);
+        app.view = ViewType::Runs;
+
+        let mut restore = |column| {
+            let sort = SortState {
+                column: SortColumn::Run(column),
+                descending: true,
+            };
+            app.view_state_cache.insert(
+                ViewType::Runs,
+                ViewState {
+                    sort: Some(sort),
+                    ..ViewState::default()
+                },
+            );
+            app.restore_view_state();
+            app.sort
+        };
+
+        // Sorts follow their column wherever it is, and are dropped once it's hidden
+        assert!(restore(RunColumn::Status).is_some());
+        assert!(restore(RunColumn::Mode).is_none());
+    }
+
+    #[test]
+    fn test_context_switch_keeps_list_positions_apart() {
+        let dir = std::env::temp_dir().join(format!("d9s-contexts-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let config_path = dir.join("config.toml");
+        fs::write(
+            &config_path,
+            r#"
+            last_context = "dev"
+            [contexts.dev]
+            url = "http://localhost:3000/graphql"
+            [contexts.prod]
+            url = "http://localhost:3001/graphql"
+            "#,
+        )
+        .unwrap();
+        let mut app = App::new(Config::load_from(config_path).unwrap(), Some(&dir));
+
+        let position = |selected_index| ViewState {
+            selected_index,
+            ..ViewState::default()
+        };
+        app.view_state_cache
+            .insert(ViewType::Pipelines, position(7));
+
+        app.execute_context_command("prod").unwrap();
+        assert!(app.view_state_cache.is_empty());
+        app.view_state_cache
+            .insert(ViewType::Pipelines, position(3));
+
+        app.execute_context_command("dev").unwrap();
+        assert_eq!(app.view_state_cache[&ViewType::Pipelines], position(7));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_header_sort_indicator() {
+        let columns = ColumnsConfig::pipelines();
+        let widths = columns.calculate_widths(120);
+
+        let header = columns.header(&widths, None);
+        assert!(!header.contains('▲') && !header.contains('▼'));
+
+        let sort = SortState {
+            column: SortColumn::Pipeline(PipelineColumn::RepositoryLocation),
+            descending: true,
+        };
+        let header = columns.header(&widths, Some(sort));
+        assert!(header.contains("REPOSITORY LOCATION ▼"));
+    }
+}
@@ -0,0 +1,144 @@
+use crate::config::Config;
+use crate::views::ViewType;
+use clap::{Parser, Subcommand, ValueEnum};
+use log::LevelFilter;
+use std::error::Error;
+use std::path::PathBuf;
+
+/// Terminal UI for Dagster
+#[derive(Debug, Parser)]
+#[command(name = "d9s", version, about)]
+pub struct Cli {
+    /// Context to use for this session, without changing `last_context`
+    #[arg(long, global = true)]
+    pub context: Option<String>,
+
+    /// Dagster GraphQL URL, overriding the context's for this session
+    #[arg(long, global = true)]
+    pub url: Option<String>,
+
+    /// Config file to use instead of ~/.config/d9s/config.toml
+    #[arg(long, global = true, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
+    /// File to write logs to, overriding `logging.path`
+    #[arg(long, global = true, value_name = "PATH")]
+    pub log_file: Option<PathBuf>,
+
+    /// Log level, overriding `logging.level`: off, error, warn, info, debug or trace
+    #[arg(long, global = true, value_name = "LEVEL")]
+    pub log_level: Option<LevelFilter>,
+
+    /// Block every action that would change the Dagster instance
+    #[arg(long, global = true)]
+    pub readonly: bool,
+
+    /// View to start in
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Start in the runs list
+    Runs {
+        /// Only show runs of this job
+        #[arg(long)]
+        job: Option<String>,
+    },
+    /// Start in the details of a run
+    Run { run_id: String },
+    /// Start in the pipelines list
+    Pipelines,
+    /// Start in the contexts list
+    Contexts,
+    /// Print resources without starting the TUI
+    Get {
+        #[command(subcommand)]
+        resource: GetResource,
+
+        /// Output format
+        #[arg(short, long, value_enum, default_value_t = OutputFormat::Table, global = true)]
+        output: OutputFormat,
+    },
+    /// Block until a run finishes, exiting 0 only if it succeeded
+    Wait {
+        run_id: String,
+
+        /// Also print the run's event log as it's written
+        #[arg(long)]
+        follow_logs: bool,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum GetResource {
+    /// List recent runs
+    Runs {
+        /// Only list runs of this job
+        #[arg(long)]
+        job: Option<String>,
+
+        /// How many runs to fetch, defaulting to the context's runs_limit
+        #[arg(long)]
+        limit: Option<usize>,
+
+        /// Only list runs with this status, as with `:runs` in the TUI: failed, success,
+        /// running, queued or canceled. Repeat for several
+        #[arg(long)]
+        status: Vec<String>,
+
+        /// Only list runs matching this search, as with `/` in the TUI. It looks at the
+        /// context's runs_columns, even with `-o wide`
+        #[arg(long)]
+        filter: Option<String>,
+    },
+    /// Show a single run
+    Run { run_id: String },
+    /// List pipelines
+    Pipelines {
+        /// Only list pipelines matching this search, as with `/` in the TUI
+        #[arg(long)]
+        filter: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Aligned columns, using the context's runs_columns
+    Table,
+    /// Aligned columns, with every run column
+    Wide,
+    Json,
+    Yaml,
+}
+
+impl Cli {
+    /// Load the config file and apply the session's context override
+    pub fn load_config(&self) -> Result<Config, Box<dyn Error>> {
+        // A broken config stops d9s rather than being replaced with defaults,
+        // which the next save would write over the user's file
+        let mut config = match &self.config {
+            Some(path) => Config::load_from(path.clone())?,
+            None => Config::load()?,
+        };
+
+        if let Some(context) = &self.context {
+            config.override_context(context)?;
+        }
+
+        Ok(config)
+    }
+
+    /// The view requested on the command line, if any
+    pub fn start_view(&self) -> Option<ViewType> {
+        match self.command.as_ref()? {
+            Command::Runs { job: None } => Some(ViewType::Runs),
+            Command::Runs { job: Some(job) } => Some(ViewType::PipelineRuns(job.clone())),
+            Command::Run { run_id } => Some(ViewType::Run(run_id.clone())),
+            Command::Pipelines => Some(ViewType::Pipelines),
+            Command::Contexts => Some(ViewType::Contexts),
+            Command::Get { .. } | Command::Wait { .. } => None,
+        }
+    }
+}
@@ -0,0 +1,239 @@
+use crate::config::{ContextConfig, TlsConfig};
+use crate::error::DagsterError;
+use graphql_client::{GraphQLQuery, Response};
+use reqwest::header::{HeaderMap, HeaderValue, USER_AGENT};
+use reqwest::{Certificate, ClientBuilder, Identity, NoProxy, Proxy};
+use std::error::Error;
+use std::fmt::Debug;
+use std::fs;
+use std::path::Path;
+use std::time::Instant;
+
+const DEFAULT_USER_AGENT: &str = "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/58.0.3029.110 Safari/537.36 Edge/16.16299";
+
+/// Request timeout used when a context doesn't set `timeout_secs`
+pub const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+/// GraphQL client for a single Dagster context
+///
+/// Cloning is cheap and shares the underlying connection pool, so pollers can
+/// take a copy without holding the app lock for the whole request.
+#[derive(Debug, Clone)]
+pub struct DagsterClient {
+    url: String,
+    /// Why the context's settings couldn't be applied, in which case nothing is sent
+    http: Result<reqwest::Client, String>,
+}
+
+impl DagsterClient {
+    /// Build a client with the context's URL, auth, headers, timeout and TLS settings
+    pub fn for_context(context: &ContextConfig) -> Result<Self, Box<dyn Error>> {
+        let mut builder =
+            Self::builder(context).default_headers(Self::headers(context.request_headers()?));
+        if let Some(tls) = &context.tls {
+            builder = apply_tls(builder, tls)?;
+        }
+        if let Some(proxy) = &context.proxy {
+            builder = apply_proxy(builder, proxy, context.no_proxy.as_deref())?;
+        }
+
+        Ok(Self {
+            url: context.url.clone(),
+            http: Ok(builder.build()?),
+        })
+    }
+
+    /// A client for a context whose auth, TLS or proxy settings couldn't be applied
+    ///
+    /// Every request fails with `error` rather than reaching the server without the
+    /// credentials or by a route the user didn't configure.
+    pub fn failed(context: &ContextConfig, error: String) -> Self {
+        Self {
+            url: context.url.clone(),
+            http: Err(error),
+        }
+    }
+
+    fn builder(context: &ContextConfig) -> ClientBuilder {
+        reqwest::Client::builder().timeout(context.timeout())
+    }
+
+    fn headers(mut headers: HeaderMap) -> HeaderMap {
+        headers
+            .entry(USER_AGENT)
+            .or_insert(HeaderValue::from_static(DEFAULT_USER_AGENT));
+        headers
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Send a query or mutation and decode its response
+    ///
+    /// GraphQL `errors` are returned whenever the server sends no data, so the
+    /// user sees why a request failed.
+    pub async fn execute<Q: GraphQLQuery>(
+        &self,
+        variables: Q::Variables,
+    ) -> Result<Q::ResponseData, DagsterError>
+    where
+        Q::ResponseData: Debug,
+    {
+        let operation = std::any::type_name::<Q>()
+            .rsplit("::")
+            .next()
+            .unwrap_or("Query");
+        let started = Instant::now();
+        let result = self.send::<Q>(operation, variables).await;
+        let elapsed = started.elapsed().as_millis();
+        match &result {
+            Ok(_) => log::debug!("{} took {}ms", operation, elapsed),
+            Err(e) => log::warn!("{} failed after {}ms: {}", operation, elapsed, e),
+        }
+        result
+    }
+
+    async fn send<Q: GraphQLQuery>(
+        &self,
+        operation: &str,
+        variables: Q::Variables,
+    ) -> Result<Q::ResponseData, DagsterError>
+    where
+        Q::ResponseData: Debug,
+    {
+        let http = self
+            .http
+            .as_ref()
+            .map_err(|e| DagsterError::Config(e.clone()))?;
+        let request_body = Q::build_query(variables);
+        log::debug!("Sending {} to {}", operation, self.url);
+
+        let res = http.post(&self.url).json(&request_body).send().await?;
+
+        let status = res.status();
+        let body = res.text().await?;
+        log::debug!("{} status: {}", operation, status);
+
+        let http_error = || DagsterError::Http {
+            status: status.as_u16(),
+            body: body.trim().to_string(),
+        };
+
+        let response_body: Response<Q::ResponseData> = match serde_json::from_str(&body) {
+            Ok(response_body) => response_body,
+            Err(_) if !status.is_success() => return Err(http_error()),
+            Err(e) => return Err(e.into()),
+        };
+        // Responses can be large, so they're only logged at trace level
+        log::trace!("{} response: {:?}", operation, response_body);
+
+        let errors = response_body
+            .errors
+            .unwrap_or_default()
+            .into_iter()
+            .map(|error| error.message)
+            .collect::<Vec<_>>();
+
+        match response_body.data {
+            Some(data) => {
+                if !errors.is_empty() {
+                    log::warn!("{} returned partial data: {}", operation, errors.join("; "));
+                }
+                Ok(data)
+            }
+            None if !errors.is_empty() => Err(DagsterError::GraphQL(errors)),
+            None if !status.is_success() => Err(http_error()),
+            None => Err(DagsterError::Decode(format!(
+                "{} returned no data",
+                operation
+            ))),
+        }
+    }
+}
+
+/// Trust the context's CA bundle, present its client certificate, or skip verification
+fn apply_tls(mut builder: ClientBuilder, tls: &TlsConfig) -> Result<ClientBuilder, Box<dyn Error>> {
+    if let Some(path) = &tls.ca_cert {
+        for certificate in Certificate::from_pem_bundle(&read_pem(path)?)? {
+            builder = builder.add_root_certificate(certificate);
+        }
+    }
+
+    match (&tls.client_cert, &tls.client_key) {
+        (Some(cert), Some(key)) => {
+            let identity = Identity::from_pkcs8_pem(&read_pem(cert)?, &read_pem(key)?)?;
+            builder = builder.identity(identity);
+        }
+        (None, None) => {}
+        _ => {
+            return Err(Box::new(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "tls.client_cert and tls.client_key must be set together",
+            )));
+        }
+    }
+
+    if tls.insecure_skip_verify {
+        log::warn!("TLS certificate verification is disabled for this context");
+        builder = builder.danger_accept_invalid_certs(true);
+    }
+
+    Ok(builder)
+}
+
+/// Route requests through the context's proxy instead of the `*_PROXY` environment variables
+fn apply_proxy(
+    builder: ClientBuilder,
+    proxy: &str,
+    no_proxy: Option<&str>,
+) -> Result<ClientBuilder, Box<dyn Error>> {
+    if proxy.eq_ignore_ascii_case("none") {
+        return Ok(builder.no_proxy());
+    }
+
+    let no_proxy = match no_proxy {
+        Some(hosts) => NoProxy::from_string(hosts),
+        None => NoProxy::from_env(),
+    };
+    let proxy = Proxy::all(proxy)
+        .map_err(|e| format!("Invalid proxy {}: {}", proxy, e))?
+        .no_proxy(no_proxy);
+
+    Ok(builder.proxy(proxy))
+}
+
+fn read_pem(path: &Path) -> Result<Vec<u8>, Box<dyn Error>> {
+    fs::read(path).map_err(|e| {
+        Box::new(std::io::Error::new(
+            e.kind(),
+            format!("Can't read {}: {}", path.display(), e),
+        )) as Box<dyn Error>
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_runs::{RunsQuery, runs_query};
+
+    #[tokio::test]
+    async fn test_failed_client_sends_nothing() {
+        // Nothing listens on the discard port, so a request would fail differently
+        let context = ContextConfig {
+            url: "http://127.0.0.1:9/graphql".to_string(),
+            ..ContextConfig::default()
+        };
+        let client = DagsterClient::failed(&context, "token_cmd failed".to_string());
+
+        let variables = runs_query::Variables {
+            pipeline_name: String::new(),
+            cursor: String::new(),
+            run_ids: vec![],
+            statuses: vec![],
+            limit: 1,
+        };
+        let error = client.execute::<RunsQuery>(variables).await.unwrap_err();
+        assert!(matches!(error, DagsterError::Config(message) if message == "token_cmd failed"));
+    }
+}
@@ -0,0 +1,324 @@
+use std::fs;
+use std::path::PathBuf;
+
+/// Commands kept in the history file
+const MAX_HISTORY: usize = 500;
+
+/// An edit to the command line from a key press in command mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandEdit {
+    Insert(char),
+    Backspace,
+    Delete,
+    Left,
+    Right,
+    WordLeft,
+    WordRight,
+    Home,
+    End,
+    /// Delete the word before the cursor
+    DeleteWord,
+    /// Delete everything before the cursor
+    DeleteToStart,
+    HistoryPrevious,
+    HistoryNext,
+}
+
+/// The `:` command line: the input with its cursor, history and tab completion
+#[derive(Debug, Default)]
+pub struct CommandLine {
+    input: String,
+    /// Byte offset into `input`, always on a char boundary
+    cursor: usize,
+    /// Why the last command was rejected, shown until the next edit
+    pub error: Option<String>,
+    history: Vec<String>,
+    /// The history entry being shown, and the input to return to past the newest one
+    browsing: Option<(usize, String)>,
+    completion: Option<Completion>,
+    /// Where history is persisted across sessions, if anywhere
+    history_path: Option<PathBuf>,
+}
+
+/// Candidates offered by the last Tab, cycled through by the next ones
+#[derive(Debug)]
+struct Completion {
+    /// Where the word being completed starts
+    start: usize,
+    candidates: Vec<String>,
+    index: Option<usize>,
+}
+
+impl CommandLine {
+    /// A command line with the history saved at `path`
+    pub fn with_history(path: PathBuf) -> Self {
+        let history = fs::read_to_string(&path)
+            .map(|contents| contents.lines().map(str::to_string).collect())
+            .unwrap_or_default();
+
+        Self {
+            history,
+            history_path: Some(path),
+            ..Self::default()
+        }
+    }
+
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Candidates from the last Tab when it was ambiguous
+    pub fn completions(&self) -> &[String] {
+        self.completion
+            .as_ref()
+            .map_or(&[], |completion| &completion.candidates)
+    }
+
+    /// Replace the input, leaving the cursor at the end
+    pub fn set(&mut self, input: impl Into<String>) {
+        self.replace(input.into());
+        self.browsing = None;
+        self.error = None;
+    }
+
+    pub fn clear(&mut self) {
+        self.set(String::new());
+    }
+
+    fn replace(&mut self, input: String) {
+        self.input = input;
+        self.cursor = self.input.len();
+        self.completion = None;
+    }
+
+    pub fn edit(&mut self, edit: CommandEdit) {
+        self.error = None;
+        self.completion = None;
+        if !matches!(
+            edit,
+            CommandEdit::HistoryPrevious | CommandEdit::HistoryNext
+        ) {
+            self.browsing = None;
+        }
+
+        match edit {
+            CommandEdit::Insert(c) => {
+                self.input.insert(self.cursor, c);
+                self.cursor += c.len_utf8();
+            }
+            CommandEdit::Backspace => {
+                let start = self.previous_char();
+                self.input.drain(start..self.cursor);
+                self.cursor = start;
+            }
+            CommandEdit::Delete => {
+                let end = self.next_char();
+                self.input.drain(self.cursor..end);
+            }
+            CommandEdit::Left => self.cursor = self.previous_char(),
+            CommandEdit::Right => self.cursor = self.next_char(),
+            CommandEdit::WordLeft => self.cursor = self.previous_word(),
+            CommandEdit::WordRight => self.cursor = self.next_word(),
+            CommandEdit::Home => self.cursor = 0,
+            CommandEdit::End => self.cursor = self.input.len(),
+            CommandEdit::DeleteWord => {
+                let start = self.previous_word();
+                self.input.drain(start..self.cursor);
+                self.cursor = start;
+            }
+            CommandEdit::DeleteToStart => {
+                self.input.drain(..self.cursor);
+                self.cursor = 0;
+            }
+            CommandEdit::HistoryPrevious => {
+                let (index, draft) = match self.browsing.take() {
+                    Some((index, draft)) => (index.saturating_sub(1), draft),
+                    None if self.history.is_empty() => return,
+                    None => (self.history.len() - 1, self.input.clone()),
+                };
+                self.replace(self.history[index].clone());
+                self.browsing = Some((index, draft));
+            }
+            CommandEdit::HistoryNext => match self.browsing.take() {
+                Some((index, draft)) if index + 1 < self.history.len() => {
+                    self.replace(self.history[index + 1].clone());
+                    self.browsing = Some((index + 1, draft));
+                }
+                Some((_, draft)) => self.replace(draft),
+                None => {}
+            },
+        }
+    }
+
+    fn previous_char(&self) -> usize {
+        self.input[..self.cursor]
+            .char_indices()
+            .next_back()
+            .map_or(0, |(i, _)| i)
+    }
+
+    fn next_char(&self) -> usize {
+        self.input[self.cursor..]
+            .chars()
+            .next()
+            .map_or(self.cursor, |c| self.cursor + c.len_utf8())
+    }
+
+    /// Start of the word before the cursor, skipping whitespace first
+    fn previous_word(&self) -> usize {
+        word_start(self.input[..self.cursor].trim_end())
+    }
+
+    /// End of the word after the cursor, skipping whitespace first
+    fn next_word(&self) -> usize {
+        let after = &self.input[self.cursor..];
+        let word = after.len() - after.trim_start().len();
+        after[word..]
+            .find(char::is_whitespace)
+            .map_or(self.input.len(), |i| self.cursor + word + i)
+    }
+
+    /// The complete words before the cursor and the partial word being typed
+    pub fn completion_context(&self) -> (Vec<&str>, &str) {
+        let start = self.word_start();
+        (
+            self.input[..start].split_whitespace().collect(),
+            &self.input[start..self.cursor],
+        )
+    }
+
+    fn word_start(&self) -> usize {
+        match &self.completion {
+            Some(completion) => completion.start,
+            None => word_start(&self.input[..self.cursor]),
+        }
+    }
+
+    /// Complete the word before the cursor from `candidates`
+    ///
+    /// A single match is filled in, several are narrowed to their common prefix
+    /// and listed, and pressing Tab again cycles through them.
+    pub fn complete(&mut self, candidates: &[String]) {
+        self.error = None;
+        if let Some(completion) = &mut self.completion {
+            let index = completion
+                .index
+                .map_or(0, |i| (i + 1) % completion.candidates.len());
+            completion.index = Some(index);
+            let (start, word) = (completion.start, completion.candidates[index].clone());
+            self.input.replace_range(start..self.cursor, &word);
+            self.cursor = start + word.len();
+            return;
+        }
+
+        let start = self.word_start();
+        let prefix = &self.input[start..self.cursor];
+        let mut matches: Vec<String> = candidates
+            .iter()
+            .filter(|candidate| candidate.starts_with(prefix))
+            .cloned()
+            .collect();
+        matches.sort();
+        matches.dedup();
+
+        let word = match matches.as_slice() {
+            [] => return,
+            [only] if self.cursor == self.input.len() => format!("{} ", only),
+            [only] => only.clone(),
+            [first, rest @ ..] => rest.iter().fold(first.clone(), |common, candidate| {
+                let len = common
+                    .char_indices()
+                    .zip(candidate.chars())
+                    .take_while(|((_, a), b)| a == b)
+                    .last()
+                    .map_or(0, |((i, a), _)| i + a.len_utf8());
+                common[..len].to_string()
+            }),
+        };
+        self.input.replace_range(start..self.cursor, &word);
+        self.cursor = start + word.len();
+        if matches.len() > 1 {
+            self.completion = Some(Completion {
+                start,
+                candidates: matches,
+                index: None,
+            });
+        }
+    }
+
+    /// Add the current input to the history and save it
+    pub fn record(&mut self) {
+        let command = self.input.trim();
+        if command.is_empty() || self.history.last().is_some_and(|last| last == command) {
+            return;
+        }
+        self.history.push(command.to_string());
+        if self.history.len() > MAX_HISTORY {
+            self.history.drain(..self.history.len() - MAX_HISTORY);
+        }
+
+        if let Some(path) = &self.history_path {
+            let result = path
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|_| fs::write(path, self.history.join("\n") + "\n"));
+            if let Err(e) = result {
+                log::warn!(
+                    "Failed to save command history to {}: {}",
+                    path.display(),
+                    e
+                );
+            }
+        }
+    }
+}
+
+/// Where the last word of `text` starts
+fn word_start(text: &str) -> usize {
+    text.char_indices()
+        .rfind(|(_, c)| c.is_whitespace())
+        .map_or(0, |(i, c)| i + c.len_utf8())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_line_editing() {
+        let mut line = CommandLine::default();
+        line.set("context prod");
+        line.edit(CommandEdit::DeleteWord);
+        assert_eq!(line.input(), "context ");
+        line.edit(CommandEdit::Home);
+        line.edit(CommandEdit::Insert('x'));
+        assert_eq!((line.input(), line.cursor()), ("xcontext ", 1));
+
+        // Several matches narrow to their common prefix, then Tab cycles
+        let candidates = ["context".to_string(), "context-add".to_string()];
+        line.set("con");
+        line.complete(&candidates);
+        assert_eq!(line.input(), "context");
+        line.complete(&candidates);
+        assert_eq!(line.input(), "context");
+        line.complete(&candidates);
+        assert_eq!(line.input(), "context-add");
+
+        line.set("runs");
+        line.record();
+        line.set("pipelines");
+        line.record();
+        line.set("draft");
+        line.edit(CommandEdit::HistoryPrevious);
+        line.edit(CommandEdit::HistoryPrevious);
+        assert_eq!(line.input(), "runs");
+        line.edit(CommandEdit::HistoryNext);
+        assert_eq!(line.input(), "pipelines");
+        line.edit(CommandEdit::HistoryNext);
+        assert_eq!(line.input(), "draft");
+    }
+}
@@ -1,13 +1,56 @@
+use crate::aliases;
+use crate::app::SortState;
+use crate::client::DEFAULT_TIMEOUT_SECS;
+use crate::keys::{Keymap, KeysConfig};
+use crate::theme::ThemeConfig;
+use crate::views::ViewType;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use log::LevelFilter;
+use reqwest::header::{AUTHORIZATION, HeaderMap, HeaderName, HeaderValue};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::error::Error;
 use std::fs;
 use std::path::PathBuf;
+use std::process::Command;
+use std::time::{Duration, SystemTime};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct ContextConfig {
     pub url: String,
     pub runs_limit: Option<usize>,
+    /// Columns shown in the runs table, in display order
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runs_columns: Option<Vec<RunColumn>>,
+    /// Allow irreversible actions such as deleting runs
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub allow_destructive: bool,
+    /// Which Dagster mutations are allowed from this context
+    #[serde(default, skip_serializing_if = "ContextMode::is_default")]
+    pub mode: ContextMode,
+    /// Flag the context as production with a banner in the context bar
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub production: bool,
+    /// Credentials sent with every request to this context
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth: Option<AuthConfig>,
+    /// Extra headers sent with every request to this context
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub headers: HashMap<String, String>,
+    /// Request timeout in seconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+    /// Custom CA, client certificate and verification settings
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<TlsConfig>,
+    /// HTTP or SOCKS proxy URL, or `none` to ignore proxy environment variables
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proxy: Option<String>,
+    /// Comma-separated hosts that bypass `proxy`; defaults to `NO_PROXY`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub no_proxy: Option<String>,
 }
 
 impl Default for ContextConfig {
@@ -15,14 +58,506 @@ impl Default for ContextConfig {
         Self {
             url: "http://localhost:3000/graphql".to_string(),
             runs_limit: Some(20),
+            runs_columns: None,
+            allow_destructive: false,
+            mode: ContextMode::default(),
+            production: false,
+            auth: None,
+            headers: HashMap::new(),
+            timeout_secs: None,
+            tls: None,
+            proxy: None,
+            no_proxy: None,
+        }
+    }
+}
+
+impl ContextConfig {
+    /// The configured runs table columns, or the default set
+    pub fn runs_columns(&self) -> Vec<RunColumn> {
+        match &self.runs_columns {
+            Some(columns) if !columns.is_empty() => columns.clone(),
+            _ => RunColumn::DEFAULT.to_vec(),
+        }
+    }
+
+    /// Whether server certificates go unverified for this context
+    pub fn insecure_tls(&self) -> bool {
+        self.tls
+            .as_ref()
+            .is_some_and(|tls| tls.insecure_skip_verify)
+    }
+
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout_secs.unwrap_or(DEFAULT_TIMEOUT_SECS))
+    }
+
+    /// Build the headers for requests to this context, resolving any secrets
+    pub fn request_headers(&self) -> Result<HeaderMap, Box<dyn Error>> {
+        let mut headers = HeaderMap::new();
+
+        for (name, value) in &self.headers {
+            headers.insert(
+                HeaderName::from_bytes(name.as_bytes())?,
+                HeaderValue::from_str(value)?,
+            );
+        }
+
+        if let Some(auth) = &self.auth {
+            let secret = auth.resolve_secret()?;
+            let (name, value) = match auth.kind {
+                AuthKind::Bearer => (AUTHORIZATION, format!("Bearer {}", secret)),
+                AuthKind::DagsterCloud => {
+                    (HeaderName::from_static("dagster-cloud-api-token"), secret)
+                }
+                AuthKind::Basic => {
+                    let username = auth.username.as_deref().unwrap_or_default();
+                    let credentials = STANDARD.encode(format!("{}:{}", username, secret));
+                    (AUTHORIZATION, format!("Basic {}", credentials))
+                }
+            };
+            let mut value = HeaderValue::from_str(&value)?;
+            value.set_sensitive(true);
+            headers.insert(name, value);
+        }
+
+        Ok(headers)
+    }
+
+    /// This context pointed at another URL, e.g. from `--url` or `:url`
+    ///
+    /// Credentials are only kept for the context's own origin, so they're never sent to a
+    /// server the context wasn't configured for.
+    pub fn with_url(&self, url: &str) -> Result<ContextConfig, String> {
+        validate_url(url)?;
+        let mut context = ContextConfig {
+            url: url.to_string(),
+            ..self.clone()
+        };
+
+        let origin = |url: &str| reqwest::Url::parse(url).ok().map(|url| url.origin());
+        if origin(url) != origin(&self.url) {
+            log::info!(
+                "{} is outside the context's origin; not sending its credentials",
+                url
+            );
+            context.auth = None;
+            context.headers.clear();
+            if let Some(tls) = &mut context.tls {
+                tls.client_cert = None;
+                tls.client_key = None;
+            }
+        }
+        Ok(context)
+    }
+}
+
+/// TLS settings for instances behind a private CA or requiring mutual TLS
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TlsConfig {
+    /// PEM bundle of extra CA certificates to trust
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ca_cert: Option<PathBuf>,
+    /// PEM client certificate for mutual TLS, used together with `client_key`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_cert: Option<PathBuf>,
+    /// PKCS#8 PEM private key for `client_cert`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_key: Option<PathBuf>,
+    /// Accept any server certificate. This disables protection against
+    /// man-in-the-middle attacks, so only use it against test instances
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub insecure_skip_verify: bool,
+}
+
+/// How a context authenticates against its Dagster instance
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AuthConfig {
+    pub kind: AuthKind,
+    /// Username for basic auth; the secret is the password
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    /// The secret itself. Prefer `token_env` or `token_cmd` to keep it out of this file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+    /// Environment variable holding the secret
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_env: Option<String>,
+    /// Shell command whose output is the secret, e.g. `pass show dagster`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_cmd: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuthKind {
+    /// `Authorization: Bearer <token>`
+    Bearer,
+    /// `Dagster-Cloud-Api-Token: <token>`, as used by Dagster+
+    DagsterCloud,
+    /// `Authorization: Basic <username:token>`
+    Basic,
+}
+
+impl AuthConfig {
+    /// Look up the secret from, in order, `token_cmd`, `token_env` and `token`
+    fn resolve_secret(&self) -> Result<String, Box<dyn Error>> {
+        if let Some(cmd) = &self.token_cmd {
+            let output = Command::new("sh").arg("-c").arg(cmd).output()?;
+            if !output.status.success() {
+                return Err(Box::new(std::io::Error::other(format!(
+                    "token_cmd `{}` exited with {}",
+                    cmd, output.status
+                ))));
+            }
+            return Ok(String::from_utf8(output.stdout)?.trim().to_string());
+        }
+
+        if let Some(var) = &self.token_env {
+            return std::env::var(var).map_err(|_| {
+                Box::new(std::io::Error::new(
+                    std::io::ErrorKind::NotFound,
+                    format!("Environment variable {} is not set", var),
+                )) as Box<dyn Error>
+            });
+        }
+
+        self.token.clone().ok_or_else(|| {
+            Box::new(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "Auth is configured without token, token_env or token_cmd",
+            )) as Box<dyn Error>
+        })
+    }
+}
+
+/// Safety mode controlling which mutations a context allows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ContextMode {
+    /// No mutations at all
+    ReadOnly,
+    /// Every mutation asks for confirmation first
+    #[default]
+    Confirm,
+    /// Reversible mutations run immediately; deletes still need a typed confirmation
+    Unrestricted,
+}
+
+impl ContextMode {
+    fn is_default(&self) -> bool {
+        *self == ContextMode::default()
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ContextMode::ReadOnly => "read-only",
+            ContextMode::Confirm => "confirm",
+            ContextMode::Unrestricted => "unrestricted",
+        }
+    }
+}
+
+/// A column that can be shown in the runs table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RunColumn {
+    RunId,
+    Pipeline,
+    Status,
+    Duration,
+    StartTime,
+    EndTime,
+    Tags,
+    LaunchedBy,
+    Partition,
+    CodeLocation,
+    Mode,
+}
+
+impl RunColumn {
+    /// Columns shown when a context doesn't configure its own
+    pub const DEFAULT: [RunColumn; 5] = [
+        RunColumn::RunId,
+        RunColumn::Pipeline,
+        RunColumn::Status,
+        RunColumn::Duration,
+        RunColumn::StartTime,
+    ];
+
+    /// Every column, in the order `-o wide` prints them
+    pub const ALL: [RunColumn; 11] = [
+        RunColumn::RunId,
+        RunColumn::Pipeline,
+        RunColumn::Status,
+        RunColumn::Duration,
+        RunColumn::StartTime,
+        RunColumn::EndTime,
+        RunColumn::LaunchedBy,
+        RunColumn::Partition,
+        RunColumn::Tags,
+        RunColumn::CodeLocation,
+        RunColumn::Mode,
+    ];
+
+    /// Column header text
+    pub fn title(&self) -> &'static str {
+        match self {
+            RunColumn::RunId => "RUN ID",
+            RunColumn::Pipeline => "PIPELINE",
+            RunColumn::Status => "STATUS",
+            RunColumn::Duration => "DURATION",
+            RunColumn::StartTime => "START TIME",
+            RunColumn::EndTime => "END TIME",
+            RunColumn::Tags => "TAGS",
+            RunColumn::LaunchedBy => "LAUNCHED BY",
+            RunColumn::Partition => "PARTITION",
+            RunColumn::CodeLocation => "CODE LOCATION",
+            RunColumn::Mode => "MODE",
         }
     }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct Config {
     pub last_context: String,
     pub contexts: HashMap<String, ContextConfig>,
+    #[serde(default, skip_serializing_if = "PollingConfig::is_default")]
+    pub polling: PollingConfig,
+    #[serde(default, skip_serializing_if = "LoggingConfig::is_default")]
+    pub logging: LoggingConfig,
+    #[serde(default, skip_serializing_if = "ThemeConfig::is_default")]
+    pub theme: ThemeConfig,
+    /// Key bindings replacing the defaults, per view
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub keys: KeysConfig,
+    /// Command aliases, e.g. `f = "runs failed"`
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub aliases: HashMap<String, String>,
+    /// Views saved with `:save`, by name
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub views: HashMap<String, SavedView>,
+    /// Reopen each context's last view, filters and list positions on startup
+    #[serde(default = "enabled", skip_serializing_if = "Clone::clone")]
+    pub restore_session: bool,
+    /// Where this config was loaded from and is saved to
+    #[serde(skip)]
+    path: Option<PathBuf>,
+    /// The on-disk `last_context` while a command-line override is active
+    #[serde(skip)]
+    saved_context: Option<String>,
+    /// Modification time of the file when d9s last loaded or saved it
+    #[serde(skip)]
+    modified: Option<SystemTime>,
+}
+
+/// A view with its filters and sort, saved with `:save` and reopened with `:view`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SavedView {
+    /// Context the view was saved in, switched to when it's opened
+    pub context: String,
+    /// e.g. `"runs"`, `"pipelines"` or `{ pipeline_runs = "nightly_etl" }`
+    pub view: ViewType,
+    /// Run statuses the runs list is limited to, e.g. `["FAILURE"]`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub statuses: Vec<String>,
+    /// The committed `/` filter
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub search: String,
+    /// Sorted column by name, e.g. `"start_time"` or `"repository_location"`, and direction
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sort: Option<SortState>,
+}
+
+impl SavedView {
+    /// One-line summary for the `:views` list, e.g. `prod: Pipeline Runs nightly_etl [FAILURE] /backfill`
+    pub fn summary(&self) -> String {
+        let mut summary = format!("{}: {}", self.context, self.view.title());
+        if let ViewType::PipelineRuns(name) | ViewType::Run(name) = &self.view {
+            summary.push_str(&format!(" {}", name));
+        }
+        if !self.statuses.is_empty() {
+            summary.push_str(&format!(" [{}]", self.statuses.join("|")));
+        }
+        if !self.search.is_empty() {
+            summary.push_str(&format!(" /{}", self.search));
+        }
+        summary
+    }
+}
+
+/// How often views refresh from Dagster
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PollingConfig {
+    /// Seconds between polls for views without their own interval
+    pub interval_secs: f64,
+    /// Per-view intervals, keyed by `runs`, `run`, `pipelines` or `debuglog`
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub views: HashMap<String, f64>,
+    /// Interval while a run in view is starting or in progress
+    pub active_interval_secs: f64,
+    /// Interval when every run in view has finished or the terminal is unfocused
+    pub idle_interval_secs: f64,
+    /// Upper bound for the exponential backoff after failed polls
+    pub max_backoff_secs: f64,
+}
+
+impl Default for PollingConfig {
+    fn default() -> Self {
+        Self {
+            interval_secs: 3.0,
+            views: HashMap::new(),
+            active_interval_secs: 1.0,
+            idle_interval_secs: 15.0,
+            max_backoff_secs: 60.0,
+        }
+    }
+}
+
+impl PollingConfig {
+    // Anything faster just hammers the server
+    const MIN_INTERVAL_SECS: f64 = 0.25;
+    // A day between polls is already as good as never
+    const MAX_INTERVAL_SECS: f64 = 86_400.0;
+    /// Keys allowed in `views`, matching `ViewType::poll_key`
+    const VIEW_KEYS: [&str; 4] = ["runs", "run", "pipelines", "debuglog"];
+
+    fn is_default(&self) -> bool {
+        *self == PollingConfig::default()
+    }
+
+    /// The configured interval for a view, falling back to the global one
+    pub fn interval(&self, view_key: Option<&str>) -> Duration {
+        let secs = view_key
+            .and_then(|key| self.views.get(key))
+            .copied()
+            .unwrap_or(self.interval_secs);
+        Self::duration(secs)
+    }
+
+    pub fn active_interval(&self) -> Duration {
+        Self::duration(self.active_interval_secs)
+    }
+
+    pub fn idle_interval(&self) -> Duration {
+        Self::duration(self.idle_interval_secs)
+    }
+
+    pub fn max_backoff(&self) -> Duration {
+        Self::duration(self.max_backoff_secs)
+    }
+
+    fn duration(secs: f64) -> Duration {
+        let min = Duration::from_secs_f64(Self::MIN_INTERVAL_SECS);
+        Duration::try_from_secs_f64(secs.clamp(Self::MIN_INTERVAL_SECS, Self::MAX_INTERVAL_SECS))
+            .unwrap_or(min)
+    }
+
+    /// Problems with the configured intervals, one message per setting
+    fn validate(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        let mut intervals = vec![
+            ("interval_secs".to_string(), self.interval_secs),
+            (
+                "active_interval_secs".to_string(),
+                self.active_interval_secs,
+            ),
+            ("idle_interval_secs".to_string(), self.idle_interval_secs),
+            ("max_backoff_secs".to_string(), self.max_backoff_secs),
+        ];
+
+        let mut views: Vec<_> = self.views.iter().collect();
+        views.sort_by_key(|(key, _)| *key);
+        for (key, secs) in views {
+            if Self::VIEW_KEYS.contains(&key.as_str()) {
+                intervals.push((format!("views.{}", key), *secs));
+            } else {
+                errors.push(format!(
+                    "polling.views.{} isn't a view; expected one of {}",
+                    key,
+                    Self::VIEW_KEYS.join(", ")
+                ));
+            }
+        }
+
+        // Also rejects NaN, which fails every comparison
+        for (name, secs) in intervals {
+            if !(Self::MIN_INTERVAL_SECS..=Self::MAX_INTERVAL_SECS).contains(&secs) {
+                errors.push(format!(
+                    "polling.{} must be between {} and {} seconds",
+                    name,
+                    Self::MIN_INTERVAL_SECS,
+                    Self::MAX_INTERVAL_SECS
+                ));
+            }
+        }
+        errors
+    }
+}
+
+/// Where d9s writes its own log, and how much of it to keep
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct LoggingConfig {
+    pub level: LevelFilter,
+    /// Log file, defaulting to `d9s.log` in the XDG state directory
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+    /// Size at which the log is rotated
+    pub max_size_mb: u64,
+    /// Rotated logs to keep next to the current one
+    pub max_files: usize,
+}
+
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        Self {
+            level: LevelFilter::Info,
+            path: None,
+            max_size_mb: 10,
+            max_files: 3,
+        }
+    }
+}
+
+impl LoggingConfig {
+    fn is_default(&self) -> bool {
+        *self == LoggingConfig::default()
+    }
+
+    /// The configured log file, or `d9s.log` in the state directory
+    pub fn path(&self) -> PathBuf {
+        self.path
+            .clone()
+            .unwrap_or_else(|| state_dir().join("d9s.log"))
+    }
+}
+
+fn enabled() -> bool {
+    true
+}
+
+/// Check that a context URL is an absolute http or https URL
+fn validate_url(url: &str) -> Result<(), String> {
+    let parsed =
+        reqwest::Url::parse(url).map_err(|e| format!("`{}` isn't a valid URL: {}", url, e))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(format!("`{}` must be an http or https URL", url));
+    }
+    Ok(())
+}
+
+/// Where d9s keeps logs and history: `~/.local/state/d9s` (or the platform's equivalent)
+pub fn state_dir() -> PathBuf {
+    dirs::state_dir()
+        .or_else(dirs::data_local_dir)
+        .unwrap_or_else(std::env::temp_dir)
+        .join("d9s")
 }
 
 impl Default for Config {
@@ -33,35 +568,164 @@ impl Default for Config {
         Self {
             last_context: "default".to_string(),
             contexts,
+            polling: PollingConfig::default(),
+            logging: LoggingConfig::default(),
+            theme: ThemeConfig::default(),
+            keys: KeysConfig::new(),
+            aliases: HashMap::new(),
+            views: HashMap::new(),
+            restore_session: true,
+            path: None,
+            saved_context: None,
+            modified: None,
         }
     }
 }
 
 impl Config {
     pub fn load() -> Result<Self, Box<dyn Error>> {
-        let config_path = Self::config_path()?;
+        Self::load_from(Self::config_path()?)
+    }
 
-        if !config_path.exists() {
-            let config = Config::default();
+    /// Load the config at `path`, creating a default one if it doesn't exist
+    pub fn load_from(path: PathBuf) -> Result<Self, Box<dyn Error>> {
+        if !path.exists() {
+            let mut config = Config {
+                path: Some(path),
+                ..Config::default()
+            };
             config.save()?;
             return Ok(config);
         }
 
-        let config_str = fs::read_to_string(config_path)?;
-        let config: Config = toml::from_str(&config_str)?;
+        let config_str = fs::read_to_string(&path).map_err(|e| {
+            std::io::Error::new(e.kind(), format!("Can't read {}: {}", path.display(), e))
+        })?;
+        let mut config: Config = toml::from_str(&config_str)
+            .map_err(|e| format!("Invalid config {}: {}", path.display(), e))?;
+        config
+            .validate()
+            .map_err(|e| format!("Invalid config {}:\n{}", path.display(), e))?;
+        config.modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+        config.path = Some(path);
+        Ok(config)
+    }
+
+    /// Check what deserializing can't: URLs, runs limits, the theme, key bindings and aliases
+    pub fn validate(&self) -> Result<(), String> {
+        let mut errors = Vec::new();
+
+        if !self.contexts.contains_key(&self.last_context) {
+            errors.push(format!(
+                "last_context `{}` isn't one of the contexts",
+                self.last_context
+            ));
+        }
+
+        let mut contexts: Vec<_> = self.contexts.iter().collect();
+        contexts.sort_by_key(|(name, _)| *name);
+        for (name, context) in contexts {
+            if let Err(e) = validate_url(&context.url) {
+                errors.push(format!("contexts.{}.url: {}", name, e));
+            }
+            if context.runs_limit == Some(0) {
+                errors.push(format!("contexts.{}.runs_limit must be at least 1", name));
+            }
+        }
+
+        errors.extend(self.polling.validate());
+        if let Err(e) = self.theme.resolve() {
+            errors.push(e.to_string());
+        }
+        if let Err(e) = Keymap::new(&self.keys) {
+            errors.push(format!("Invalid key bindings:\n{}", e));
+        }
+        if let Err(e) = aliases::validate(&self.aliases) {
+            errors.push(format!("Invalid aliases:\n{}", e));
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors.join("\n"))
+        }
+    }
+
+    /// Load the file again after it changed, keeping this session's context override
+    pub fn reload(&self) -> Result<Config, Box<dyn Error>> {
+        let path = self
+            .path
+            .clone()
+            .ok_or("The config wasn't loaded from a file")?;
+        let mut config = Config::load_from(path)?;
+        if self.saved_context.is_some() && config.contexts.contains_key(&self.last_context) {
+            config.override_context(&self.last_context)?;
+        }
         Ok(config)
     }
 
-    pub fn save(&self) -> Result<(), Box<dyn Error>> {
-        let config_path = Self::config_path()?;
+    /// The modification time of the file on disk, if it exists
+    pub fn modified_on_disk(&self) -> Option<SystemTime> {
+        let path = self.path.as_ref()?;
+        fs::metadata(path).and_then(|m| m.modified()).ok()
+    }
+
+    /// The modification time of the file when d9s last loaded or saved it
+    pub fn modified(&self) -> Option<SystemTime> {
+        self.modified
+    }
+
+    pub fn save(&mut self) -> Result<(), Box<dyn Error>> {
+        let config_path = match &self.path {
+            Some(path) => path.clone(),
+            None => Self::config_path()?,
+        };
+
+        // Edits that haven't been reloaded, e.g. because they're invalid, must not be lost
+        if self.modified.is_some() && self.modified_on_disk() != self.modified {
+            return Err(format!(
+                "{} changed since d9s loaded it; fix any errors in it so it can be reloaded",
+                config_path.display()
+            )
+            .into());
+        }
 
         // Create directory if it doesn't exist
         if let Some(parent) = config_path.parent() {
             fs::create_dir_all(parent)?;
         }
 
-        let config_str = toml::to_string_pretty(self)?;
-        fs::write(config_path, config_str)?;
+        // A session override of the context must not leak into the file
+        let config_str = match &self.saved_context {
+            Some(saved_context) => {
+                let mut value = toml::Value::try_from(&*self)?;
+                if let Some(table) = value.as_table_mut() {
+                    table.insert(
+                        "last_context".to_string(),
+                        toml::Value::String(saved_context.clone()),
+                    );
+                }
+                toml::to_string_pretty(&value)?
+            }
+            None => toml::to_string_pretty(&*self)?,
+        };
+        fs::write(&config_path, config_str)?;
+        self.modified = fs::metadata(&config_path).and_then(|m| m.modified()).ok();
+        Ok(())
+    }
+
+    /// Use another context for this session only, leaving `last_context` on disk alone
+    pub fn override_context(&mut self, name: &str) -> Result<(), Box<dyn Error>> {
+        if !self.contexts.contains_key(name) {
+            return Err(Box::new(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("Context '{}' not found", name),
+            )));
+        }
+
+        self.saved_context
+            .get_or_insert_with(|| self.last_context.clone());
+        self.last_context = name.to_string();
         Ok(())
     }
 
@@ -95,15 +759,41 @@ impl Config {
             )));
         }
 
-        self.last_context = name.to_string();
-        self.save()?;
-        Ok(())
+        self.save_change(|config| {
+            config.last_context = name.to_string();
+            // Switching explicitly replaces any command-line override
+            config.saved_context = None;
+        })
     }
 
     pub fn add_context(&mut self, name: &str, config: ContextConfig) -> Result<(), Box<dyn Error>> {
-        self.contexts.insert(name.to_string(), config);
-        self.save()?;
-        Ok(())
+        validate_url(&config.url)?;
+        if config.runs_limit == Some(0) {
+            return Err("runs_limit must be at least 1".into());
+        }
+
+        self.save_change(|config_file| {
+            config_file.contexts.insert(name.to_string(), config);
+        })
+    }
+
+    pub fn save_view(&mut self, name: &str, view: SavedView) -> Result<(), Box<dyn Error>> {
+        self.save_change(|config| {
+            config.views.insert(name.to_string(), view);
+        })
+    }
+
+    pub fn remove_view(&mut self, name: &str) -> Result<(), Box<dyn Error>> {
+        if !self.views.contains_key(name) {
+            return Err(Box::new(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("Saved view '{}' not found", name),
+            )));
+        }
+
+        self.save_change(|config| {
+            config.views.remove(name);
+        })
     }
 
     pub fn remove_context(&mut self, name: &str) -> Result<(), Box<dyn Error>> {
@@ -121,14 +811,292 @@ impl Config {
             )));
         }
 
-        self.contexts.remove(name);
+        self.save_change(|config| {
+            config.contexts.remove(name);
+
+            // If we removed current context, switch to default
+            if config.last_context == name {
+                config.last_context = "default".to_string();
+            }
+        })
+    }
+
+    /// Apply `change` and save it, undoing the change if it can't be saved
+    ///
+    /// Otherwise d9s would carry on with a context or view the file doesn't have, e.g. using
+    /// one context's settings while still connected to another.
+    fn save_change(&mut self, change: impl FnOnce(&mut Self)) -> Result<(), Box<dyn Error>> {
+        let previous = (
+            self.last_context.clone(),
+            self.saved_context.clone(),
+            self.contexts.clone(),
+            self.views.clone(),
+        );
+        change(self);
+
+        let result = self.save();
+        if result.is_err() {
+            (
+                self.last_context,
+                self.saved_context,
+                self.contexts,
+                self.views,
+            ) = previous;
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::SortColumn;
 
-        // If we removed current context, switch to default
-        if self.last_context == name {
-            self.last_context = "default".to_string();
+    #[test]
+    fn test_request_headers() {
+        let mut context = ContextConfig {
+            auth: Some(AuthConfig {
+                kind: AuthKind::Basic,
+                username: Some("user".to_string()),
+                token: Some("pass".to_string()),
+                token_env: None,
+                token_cmd: Some("echo secret".to_string()),
+            }),
+            ..ContextConfig::default()
+        };
+        context
+            .headers
+            .insert("X-Team".to_string(), "data".to_string());
+
+        let headers = context.request_headers().unwrap();
+        // token_cmd takes precedence over the inline token
+        assert_eq!(headers[AUTHORIZATION], "Basic dXNlcjpzZWNyZXQ=");
+        assert_eq!(headers["x-team"], "data");
+    }
+
+    #[test]
+    fn test_with_url_drops_credentials_for_other_origins() {
+        let mut context = ContextConfig {
+            url: "https://dagster.example.com/graphql".to_string(),
+            auth: Some(AuthConfig {
+                kind: AuthKind::Bearer,
+                username: None,
+                token: Some("secret".to_string()),
+                token_env: None,
+                token_cmd: None,
+            }),
+            ..ContextConfig::default()
+        };
+        context
+            .headers
+            .insert("X-Team".to_string(), "data".to_string());
+
+        let same_origin = context
+            .with_url("https://dagster.example.com/other/graphql")
+            .unwrap();
+        assert_eq!(same_origin.auth, context.auth);
+        assert_eq!(same_origin.headers, context.headers);
+
+        for url in [
+            "https://evil.example.com/graphql",
+            "http://dagster.example.com/graphql",
+            "https://dagster.example.com:8443/graphql",
+        ] {
+            let other = context.with_url(url).unwrap();
+            assert_eq!(other.url, url);
+            assert!(other.auth.is_none() && other.headers.is_empty());
         }
 
-        self.save()?;
-        Ok(())
+        assert!(context.with_url("dagster.example.com/graphql").is_err());
+    }
+
+    #[test]
+    fn test_override_context_is_not_saved() {
+        let path = std::env::temp_dir().join(format!("d9s-test-{}.toml", std::process::id()));
+        let mut config = Config::load_from(path.clone()).unwrap();
+        config
+            .add_context("staging", ContextConfig::default())
+            .unwrap();
+
+        config.override_context("staging").unwrap();
+        config.save().unwrap();
+        assert_eq!(config.last_context, "staging");
+        assert_eq!(
+            Config::load_from(path.clone()).unwrap().last_context,
+            "default"
+        );
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_config_validation() {
+        let path = std::env::temp_dir().join(format!("d9s-invalid-{}.toml", std::process::id()));
+        let load = |contents: &str| {
+            fs::write(&path, contents).unwrap();
+            Config::load_from(path.clone())
+                .map(|_| ())
+                .unwrap_err()
+                .to_string()
+        };
+
+        let error = load(
+            r#"
+            last_context = "prod"
+            [contexts.prod]
+            url = "localhost:3000/graphql"
+            runs_limit = 0
+            "#,
+        );
+        assert!(
+            error.contains(
+                "contexts.prod.url: `localhost:3000/graphql` must be an http or https URL"
+            )
+        );
+        assert!(error.contains("contexts.prod.runs_limit must be at least 1"));
+
+        let error = load(
+            r#"
+            last_context = "prod"
+            [contexts.prod]
+            url = "http://localhost:3000/graphql"
+            runs_limt = 50
+            "#,
+        );
+        assert!(error.contains("unknown field `runs_limt`"));
+
+        let error = load(
+            r#"
+            last_context = "default"
+            [contexts.default]
+            url = "http://localhost:3000/graphql"
+            [polling]
+            interval_secs = inf
+            max_backoff_secs = 1e300
+            views = { runs = 0.0, job = 5.0 }
+            "#,
+        );
+        assert!(error.contains("polling.interval_secs must be between 0.25 and 86400 seconds"));
+        assert!(error.contains("polling.max_backoff_secs must be between"));
+        assert!(error.contains("polling.views.runs must be between"));
+        assert!(error.contains("polling.views.job isn't a view"));
+
+        // Edits that couldn't be loaded aren't overwritten
+        fs::write(&path, "last_context = \"default\"\n[contexts.default]\nurl = \"http://localhost:3000/graphql\"\n").unwrap();
+        let mut config = Config::load_from(path.clone()).unwrap();
+        std::thread::sleep(Duration::from_millis(10));
+        fs::write(&path, "last_context = \"default\"\n").unwrap();
+        assert!(config.reload().is_err());
+        assert!(config.save().is_err());
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_unsaved_context_switch_is_undone() {
+        let path = std::env::temp_dir().join(format!("d9s-switch-{}.toml", std::process::id()));
+        let contents = r#"
+            last_context = "dev"
+            [contexts.dev]
+            url = "http://localhost:3000/graphql"
+            mode = "unrestricted"
+            [contexts.prod]
+            url = "https://dagster.example.com/graphql"
+            mode = "read-only"
+            "#;
+        fs::write(&path, contents).unwrap();
+        let mut config = Config::load_from(path.clone()).unwrap();
+        config.override_context("prod").unwrap();
+
+        // An edit on disk that hasn't been reloaded yet blocks saving
+        std::thread::sleep(Duration::from_millis(10));
+        fs::write(&path, contents).unwrap();
+
+        assert!(config.set_context("dev").is_err());
+        assert_eq!(config.last_context, "prod");
+        assert_eq!(config.get_current_context().mode, ContextMode::ReadOnly);
+        assert!(
+            config
+                .add_context("staging", ContextConfig::default())
+                .is_err()
+        );
+        assert!(!config.contexts.contains_key("staging"));
+
+        // The session override is still kept out of the file once it can be saved
+        let mut config = config.reload().unwrap();
+        assert_eq!(config.last_context, "prod");
+        config.save().unwrap();
+        assert_eq!(Config::load_from(path.clone()).unwrap().last_context, "dev");
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_saved_view_round_trip() {
+        let config: Config = toml::from_str(
+            r#"
+            last_context = "default"
+            [contexts.default]
+            url = "http://localhost:3000/graphql"
+            [views.nightly]
+            context = "prod"
+            view = { pipeline_runs = "nightly_etl" }
+            statuses = ["FAILURE"]
+            search = "backfill"
+            sort = { column = "start_time", descending = true }
+            [views.pipelines]
+            context = "default"
+            view = "pipelines"
+            "#,
+        )
+        .unwrap();
+
+        let nightly = &config.views["nightly"];
+        assert_eq!(
+            nightly.view,
+            ViewType::PipelineRuns("nightly_etl".to_string())
+        );
+        assert_eq!(
+            nightly.summary(),
+            "prod: Pipeline Runs nightly_etl [FAILURE] /backfill"
+        );
+        assert_eq!(config.views["pipelines"].view, ViewType::Pipelines);
+        assert_eq!(
+            nightly.sort.map(|sort| sort.column),
+            Some(SortColumn::Run(RunColumn::StartTime))
+        );
+
+        let saved: Config = toml::from_str(&toml::to_string_pretty(&config).unwrap()).unwrap();
+        assert_eq!(saved.views, config.views);
+    }
+
+    #[test]
+    fn test_polling_interval() {
+        let config: Config = toml::from_str(
+            r#"
+            last_context = "default"
+            [contexts.default]
+            url = "http://localhost:3000/graphql"
+            [polling]
+            interval_secs = 5
+            views = { pipelines = 30, run = 0 }
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.polling.interval(None), Duration::from_secs(5));
+        assert_eq!(
+            config.polling.interval(Some("runs")),
+            Duration::from_secs(5)
+        );
+        assert_eq!(
+            config.polling.interval(Some("pipelines")),
+            Duration::from_secs(30)
+        );
+        assert_eq!(
+            config.polling.interval(Some("run")),
+            Duration::from_millis(250)
+        );
+        assert_eq!(config.polling.max_backoff(), Duration::from_secs(60));
     }
 }
@@ -0,0 +1,28 @@
+use crate::client::DagsterClient;
+use crate::error::DagsterError;
+use graphql_client::GraphQLQuery;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "queries/schema.json",
+    query_path = "queries/delete_run.graphql",
+    response_derives = "Debug,Clone"
+)]
+pub struct DeleteRunMutation;
+
+/// Permanently delete a run and its event logs. Returns the ID of the deleted run.
+pub async fn delete_run(client: &DagsterClient, run_id: String) -> Result<String, DagsterError> {
+    let query_variables = delete_run_mutation::Variables { run_id };
+    let data = client.execute::<DeleteRunMutation>(query_variables).await?;
+
+    use delete_run_mutation::DeleteRunMutationDeleteRun as DeleteRunResult;
+    match data.delete_run {
+        DeleteRunResult::DeletePipelineRunSuccess(success) => Ok(success.run_id),
+        DeleteRunResult::RunNotFoundError(err) => Err(DagsterError::RunNotFound(err.message)),
+        DeleteRunResult::UnauthorizedError(err) => Err(DagsterError::Dagster(err.message)),
+        DeleteRunResult::PythonError(err) => Err(DagsterError::Python {
+            message: err.message,
+            stack: err.stack,
+        }),
+    }
+}
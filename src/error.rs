@@ -0,0 +1,161 @@
+use std::error::Error;
+use std::fmt;
+
+/// Everything that can go wrong talking to a Dagster instance
+#[derive(Debug, Clone, PartialEq)]
+pub enum DagsterError {
+    /// The request never got a response (connection refused, DNS, timeout)
+    Transport(String),
+    /// The TLS handshake failed, usually because the server certificate couldn't be verified.
+    /// Holds the error chain, outermost first
+    Tls(Vec<String>),
+    /// The context's settings couldn't be applied, e.g. an unreadable certificate file
+    Config(String),
+    /// The server answered with a non-success status and no GraphQL body
+    Http { status: u16, body: String },
+    /// The response body couldn't be decoded
+    Decode(String),
+    /// The GraphQL layer rejected the request
+    GraphQL(Vec<String>),
+    /// Dagster raised an exception while resolving the request
+    Python { message: String, stack: Vec<String> },
+    /// The requested run doesn't exist (any more)
+    RunNotFound(String),
+    /// Any other error result Dagster returned, e.g. unauthorized or an invalid filter
+    Dagster(String),
+}
+
+impl DagsterError {
+    /// Short category shown as the error panel title
+    pub fn kind(&self) -> &'static str {
+        match self {
+            DagsterError::Transport(_) => "Connection error",
+            DagsterError::Tls(_) => "TLS error",
+            DagsterError::Config(_) => "Configuration error",
+            DagsterError::Http { .. } => "HTTP error",
+            DagsterError::Decode(_) => "Invalid response",
+            DagsterError::GraphQL(_) => "GraphQL error",
+            DagsterError::Python { .. } => "Python error",
+            DagsterError::RunNotFound(_) => "Run not found",
+            DagsterError::Dagster(_) => "Dagster error",
+        }
+    }
+
+    /// The full error text, including every GraphQL error and the Python stack
+    pub fn details(&self) -> Vec<String> {
+        match self {
+            DagsterError::GraphQL(messages) => messages.clone(),
+            DagsterError::Tls(chain) => {
+                let mut lines = chain.clone();
+                lines.push(String::new());
+                lines.push(
+                    "If the server uses a private CA, point tls.ca_cert at its PEM bundle."
+                        .to_string(),
+                );
+                lines.push(
+                    "If it requires a client certificate, set tls.client_cert and tls.client_key."
+                        .to_string(),
+                );
+                lines
+            }
+            DagsterError::Python { message, stack } => {
+                let mut lines = vec![message.clone()];
+                if !stack.is_empty() {
+                    lines.push(String::new());
+                    lines.push("Stack trace:".to_string());
+                    lines.extend(
+                        stack
+                            .iter()
+                            .flat_map(|frame| frame.lines())
+                            .map(str::to_string),
+                    );
+                }
+                lines
+            }
+            _ => self.to_string().lines().map(str::to_string).collect(),
+        }
+    }
+}
+
+impl fmt::Display for DagsterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DagsterError::Transport(message)
+            | DagsterError::Config(message)
+            | DagsterError::Decode(message)
+            | DagsterError::RunNotFound(message)
+            | DagsterError::Dagster(message) => write!(f, "{}", message),
+            DagsterError::Http { status, body } if body.is_empty() => write!(f, "HTTP {}", status),
+            DagsterError::Http { status, body } => write!(f, "HTTP {}: {}", status, body),
+            DagsterError::GraphQL(messages) => write!(f, "{}", messages.join("; ")),
+            // The innermost cause says what was wrong with the certificate
+            DagsterError::Tls(chain) => write!(
+                f,
+                "TLS error: {}",
+                chain
+                    .last()
+                    .map(String::as_str)
+                    .unwrap_or("handshake failed")
+            ),
+            DagsterError::Python { message, .. } => write!(f, "{}", message),
+        }
+    }
+}
+
+impl Error for DagsterError {}
+
+impl From<reqwest::Error> for DagsterError {
+    fn from(e: reqwest::Error) -> Self {
+        // reqwest's own message is just "error sending request", the cause is further down
+        let mut chain = vec![e.to_string()];
+        let mut source = e.source();
+        while let Some(cause) = source {
+            chain.push(cause.to_string());
+            source = cause.source();
+        }
+
+        let is_tls = chain.iter().skip(1).any(|message| {
+            let message = message.to_lowercase();
+            ["certificate", "ssl", "tls", "handshake"]
+                .iter()
+                .any(|needle| message.contains(needle))
+        });
+
+        if is_tls {
+            DagsterError::Tls(chain)
+        } else {
+            DagsterError::Transport(chain.join(": "))
+        }
+    }
+}
+
+impl From<serde_json::Error> for DagsterError {
+    fn from(e: serde_json::Error) -> Self {
+        DagsterError::Decode(e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_python_error_details() {
+        let error = DagsterError::Python {
+            message: "Boom".to_string(),
+            stack: vec!["  File \"a.py\", line 1\n    raise\n".to_string()],
+        };
+
+        assert_eq!(error.to_string(), "Boom");
+        assert_eq!(
+            error.details(),
+            vec![
+                "Boom",
+                "",
+                "Stack trace:",
+                "  File \"a.py\", line 1",
+                "    raise"
+            ]
+        );
+    }
+}
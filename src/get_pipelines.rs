@@ -1,6 +1,8 @@
-use graphql_client::{GraphQLQuery, Response};
-use reqwest::header::USER_AGENT;
-use std::error::Error;
+use crate::client::DagsterClient;
+use crate::error::DagsterError;
+use crate::utils::run_status_name;
+use graphql_client::GraphQLQuery;
+use serde::Serialize;
 
 #[derive(GraphQLQuery)]
 #[graphql(
@@ -10,7 +12,7 @@ use std::error::Error;
 )]
 pub struct PipelinesQuery;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct Pipeline {
     pub name: String,
     pub is_asset_job: bool,
@@ -18,81 +20,55 @@ pub struct Pipeline {
     pub last_run_status: Option<String>,
 }
 
-impl Default for Pipeline {
-    fn default() -> Self {
-        Self {
-            name: String::new(),
-            is_asset_job: false,
-            repository_location: String::new(),
-            last_run_status: None,
-        }
-    }
-}
-
-pub async fn get_pipelines(
-    dagster_uri: String,
-) -> Result<Vec<Pipeline>, Box<dyn Error + Send + Sync>> {
-    let request_body = PipelinesQuery::build_query(pipelines_query::Variables {});
-
-    let client = reqwest::Client::new();
-    let res = client
-        .post(dagster_uri)
-        .header(USER_AGENT, "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/58.0.3029.110 Safari/537.36 Edge/16.16299")
-        .json(&request_body)
-        .send()
+pub async fn get_pipelines(client: &DagsterClient) -> Result<Vec<Pipeline>, DagsterError> {
+    let data = client
+        .execute::<PipelinesQuery>(pipelines_query::Variables {})
         .await?;
 
-    let status = res.status();
-    let body = res.text().await?;
-    log::debug!("Pipelines query status: {}", status);
-
-    let response_body: Response<pipelines_query::ResponseData> = serde_json::from_str(&body)?;
-    log::debug!("Pipelines query response: {:#?}", response_body);
-
-    if let Some(data) = response_body.data {
-        match data.repositories_or_error {
-            pipelines_query::PipelinesQueryRepositoriesOrError::RepositoryConnection(repo_conn) => {
-                let mut pipelines = Vec::new();
+    match data.repositories_or_error {
+        pipelines_query::PipelinesQueryRepositoriesOrError::RepositoryConnection(repo_conn) => {
+            let mut pipelines = Vec::new();
 
-                for repo_node in repo_conn.nodes {
-                    let repo_name = repo_node.name;
+            for repo_node in repo_conn.nodes {
+                let repo_name = repo_node.name;
 
-                    for pipeline in repo_node.pipelines {
-                        // Skip asset jobs
-                        if pipeline.is_asset_job {
-                            continue;
-                        }
+                for pipeline in repo_node.pipelines {
+                    // Skip asset jobs
+                    if pipeline.is_asset_job {
+                        continue;
+                    }
 
-                        // Get repository location name
-                        let repo_location =
-                            pipeline.repository.origin.repository_location_name.clone();
+                    // Get repository location name
+                    let repo_location = pipeline.repository.origin.repository_location_name.clone();
 
-                        log::debug!("Pipeline: {:?}, Repository: {:?}", pipeline.name, repo_name);
+                    log::debug!("Pipeline: {:?}, Repository: {:?}", pipeline.name, repo_name);
 
-                        // Handle runs as a Vec instead of an Option
-                        let last_run_status = if !pipeline.runs.is_empty() {
-                            Some(format!("{:?}", pipeline.runs[0].status))
-                        } else {
-                            None
-                        };
+                    // Handle runs as a Vec instead of an Option
+                    let last_run_status = if !pipeline.runs.is_empty() {
+                        Some(run_status_name(&pipeline.runs[0].status))
+                    } else {
+                        None
+                    };
 
-                        pipelines.push(Pipeline {
-                            name: pipeline.name,
-                            is_asset_job: pipeline.is_asset_job,
-                            repository_location: repo_location,
-                            last_run_status,
-                        });
-                    }
+                    pipelines.push(Pipeline {
+                        name: pipeline.name,
+                        is_asset_job: pipeline.is_asset_job,
+                        repository_location: repo_location,
+                        last_run_status,
+                    });
                 }
-
-                Ok(pipelines)
             }
-            _ => Ok(Vec::new()),
+
+            Ok(pipelines)
+        }
+        pipelines_query::PipelinesQueryRepositoriesOrError::RepositoryNotFoundError(err) => {
+            Err(DagsterError::Dagster(err.message))
+        }
+        pipelines_query::PipelinesQueryRepositoriesOrError::PythonError(err) => {
+            Err(DagsterError::Python {
+                message: err.message,
+                stack: err.stack,
+            })
         }
-    } else {
-        Err(Box::new(std::io::Error::new(
-            std::io::ErrorKind::Other,
-            "Failed to fetch pipelines data",
-        )))
     }
 }
@@ -1,6 +1,6 @@
-use graphql_client::{GraphQLQuery, Response};
-use reqwest::header::USER_AGENT;
-use std::error::Error;
+use crate::client::DagsterClient;
+use crate::error::DagsterError;
+use graphql_client::GraphQLQuery;
 
 #[derive(GraphQLQuery)]
 #[graphql(
@@ -11,33 +11,21 @@ use std::error::Error;
 pub struct RunQuery;
 
 pub async fn get_run(
+    client: &DagsterClient,
     run_id: String,
-    dagster_uri: String,
-) -> Result<run_query::ResponseData, Box<dyn Error + Send + Sync>> {
-    let query_variables = run_query::Variables { run_id };
-    let request_body = RunQuery::build_query(query_variables);
-
-    let client = reqwest::Client::new();
-    let res = client
-        .post(dagster_uri)
-        .header(USER_AGENT, "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/58.0.3029.110 Safari/537.36 Edge/16.16299")
-        .json(&request_body)
-        .send()
+) -> Result<run_query::RunQueryRunOrErrorOnRun, DagsterError> {
+    let data = client
+        .execute::<RunQuery>(run_query::Variables { run_id })
         .await?;
 
-    let status = res.status();
-    let body = res.text().await?;
-    log::debug!("Run query status: {}", status);
-
-    let response_body: Response<run_query::ResponseData> = serde_json::from_str(&body)?;
-    log::debug!("Run query response: {:#?}", response_body);
-
-    if let Some(data) = response_body.data {
-        Ok(data)
-    } else {
-        Err(Box::new(std::io::Error::new(
-            std::io::ErrorKind::Other,
-            "Failed to fetch run data",
-        )))
+    match data.run_or_error {
+        run_query::RunQueryRunOrError::Run(run) => Ok(run),
+        run_query::RunQueryRunOrError::RunNotFoundError(err) => {
+            Err(DagsterError::RunNotFound(err.message))
+        }
+        run_query::RunQueryRunOrError::PythonError(err) => Err(DagsterError::Python {
+            message: err.message,
+            stack: err.stack,
+        }),
     }
 }
@@ -0,0 +1,104 @@
+use crate::client::DagsterClient;
+use crate::error::DagsterError;
+use graphql_client::GraphQLQuery;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "queries/schema.json",
+    query_path = "queries/get_run_logs.graphql",
+    response_derives = "Debug,Clone"
+)]
+pub struct RunLogsQuery;
+
+/// A message from a run's event log
+pub struct RunLogEvent {
+    pub message: String,
+    /// Milliseconds since the epoch
+    pub timestamp: String,
+    pub level: run_logs_query::LogLevel,
+    pub step_key: Option<String>,
+}
+
+/// Every event type queried selects the same fields, but gets its own generated struct
+macro_rules! run_log_event {
+    ($event:expr, $($variant:ident),+ $(,)?) => {
+        match $event {
+            $(Events::$variant(event) => Some(RunLogEvent {
+                message: event.message,
+                timestamp: event.timestamp,
+                level: event.level,
+                step_key: event.step_key,
+            }),)+
+            _ => None,
+        }
+    };
+}
+
+/// A page of a run's event log
+pub struct RunLogs {
+    pub events: Vec<RunLogEvent>,
+    /// Pass back as `after_cursor` to fetch only newer events
+    pub cursor: String,
+    pub has_more: bool,
+}
+
+/// Fetches a run's events after `after_cursor`, or from the start when it's None.
+/// Only the event types worth following are queried, the rest are dropped
+pub async fn get_run_logs(
+    client: &DagsterClient,
+    run_id: String,
+    after_cursor: Option<String>,
+) -> Result<RunLogs, DagsterError> {
+    use run_logs_query::RunLogsQueryLogsForRunOnEventConnectionEvents as Events;
+
+    let data = client
+        .execute::<RunLogsQuery>(run_logs_query::Variables {
+            run_id,
+            after_cursor,
+        })
+        .await?;
+
+    match data.logs_for_run {
+        run_logs_query::RunLogsQueryLogsForRun::EventConnection(connection) => {
+            let events = connection
+                .events
+                .into_iter()
+                .filter_map(|event| {
+                    run_log_event!(
+                        event,
+                        RunEnqueuedEvent,
+                        RunDequeuedEvent,
+                        RunStartingEvent,
+                        RunStartEvent,
+                        RunSuccessEvent,
+                        RunFailureEvent,
+                        RunCancelingEvent,
+                        RunCanceledEvent,
+                        ExecutionStepStartEvent,
+                        ExecutionStepSuccessEvent,
+                        ExecutionStepFailureEvent,
+                        ExecutionStepSkippedEvent,
+                        ExecutionStepUpForRetryEvent,
+                        ExecutionStepRestartEvent,
+                        LogMessageEvent,
+                        EngineEvent,
+                        MaterializationEvent,
+                    )
+                })
+                .collect();
+
+            Ok(RunLogs {
+                events,
+                cursor: connection.cursor,
+                has_more: connection.has_more,
+            })
+        }
+        run_logs_query::RunLogsQueryLogsForRun::RunNotFoundError(err) => {
+            Err(DagsterError::RunNotFound(err.message))
+        }
+        run_logs_query::RunLogsQueryLogsForRun::PythonError(err) => Err(DagsterError::Python {
+            message: err.message,
+            stack: err.stack,
+        }),
+    }
+}
@@ -1,6 +1,6 @@
-use graphql_client::{GraphQLQuery, Response};
-use reqwest::header::USER_AGENT;
-use std::error::Error;
+use crate::client::DagsterClient;
+use crate::error::DagsterError;
+use graphql_client::GraphQLQuery;
 
 #[derive(GraphQLQuery)]
 #[graphql(
@@ -15,13 +15,15 @@ pub struct Variables {
     pub pipeline_name: String,
     pub cursor: String,
     pub run_ids: Vec<String>,
+    /// Only runs with these statuses, e.g. `FAILURE`; all runs when empty
+    pub statuses: Vec<String>,
 }
 
 pub async fn get_runs(
+    client: &DagsterClient,
     variables: Variables,
-    dagster_uri: String,
     runs_limit: Option<usize>,
-) -> Result<runs_query::ResponseData, Box<dyn Error + Send + Sync>> {
+) -> Result<Vec<runs_query::RunsQueryRunsOrErrorOnRunsResults>, DagsterError> {
     let limit = runs_limit.unwrap_or(20);
 
     if !variables.pipeline_name.is_empty() {
@@ -32,33 +34,36 @@ pub async fn get_runs(
         pipeline_name: variables.pipeline_name,
         cursor: variables.cursor,
         run_ids: variables.run_ids,
+        statuses: variables.statuses.into_iter().map(run_status).collect(),
         limit: limit as i64,
     };
 
-    let request_body = RunsQuery::build_query(query_variables);
-    log::debug!("Requesting runs from {dagster_uri}");
+    let data = client.execute::<RunsQuery>(query_variables).await?;
 
-    let client = reqwest::Client::new();
-    let res = client
-        .post(dagster_uri)
-        .header(USER_AGENT, "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/58.0.3029.110 Safari/537.36 Edge/16.16299")
-        .json(&request_body)
-        .send()
-        .await?;
-
-    let status = res.status();
-    let body = res.text().await?;
-    log::debug!("Runs query status: {}", status);
-
-    let response_body: Response<runs_query::ResponseData> = serde_json::from_str(&body)?;
-    log::debug!("Runs query response: {:#?}", response_body);
+    match data.runs_or_error {
+        runs_query::RunsQueryRunsOrError::Runs(runs) => Ok(runs.results),
+        runs_query::RunsQueryRunsOrError::InvalidPipelineRunsFilterError(err) => {
+            Err(DagsterError::Dagster(err.message))
+        }
+        runs_query::RunsQueryRunsOrError::PythonError(err) => Err(DagsterError::Python {
+            message: err.message,
+            stack: err.stack,
+        }),
+    }
+}
 
-    if let Some(data) = response_body.data {
-        Ok(data)
-    } else {
-        Err(Box::new(std::io::Error::new(
-            std::io::ErrorKind::Other,
-            "Failed to fetch runs data",
-        )))
+fn run_status(status: String) -> runs_query::RunStatus {
+    use runs_query::RunStatus;
+    match status.as_str() {
+        "QUEUED" => RunStatus::QUEUED,
+        "NOT_STARTED" => RunStatus::NOT_STARTED,
+        "MANAGED" => RunStatus::MANAGED,
+        "STARTING" => RunStatus::STARTING,
+        "STARTED" => RunStatus::STARTED,
+        "SUCCESS" => RunStatus::SUCCESS,
+        "FAILURE" => RunStatus::FAILURE,
+        "CANCELING" => RunStatus::CANCELING,
+        "CANCELED" => RunStatus::CANCELED,
+        _ => RunStatus::Other(status),
     }
 }
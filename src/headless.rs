@@ -0,0 +1,291 @@
+use crate::cli::{GetResource, OutputFormat};
+use crate::client::DagsterClient;
+use crate::config::{Config, RunColumn};
+use crate::error::DagsterError;
+use crate::get_pipelines::{Pipeline, get_pipelines};
+use crate::get_run::get_run;
+use crate::get_run_logs::get_run_logs;
+use crate::get_runs::{Variables, get_runs};
+use crate::search::fuzzy_match;
+use crate::utils::{is_known_run_status, is_run_finished, run_statuses};
+use crate::views::{Run, pipeline_row, run_cell, run_row};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::error::Error;
+use tokio::time::sleep;
+
+/// Runs `d9s get`: prints the result to stdout, without starting the TUI
+pub async fn get(
+    config: &Config,
+    url: Option<String>,
+    resource: &GetResource,
+    output: OutputFormat,
+) -> Result<(), Box<dyn Error>> {
+    let context = config.get_current_context();
+    let client = connect(config, url)?;
+
+    let columns = match output {
+        OutputFormat::Wide => RunColumn::ALL.to_vec(),
+        _ => context.runs_columns(),
+    };
+
+    match resource {
+        GetResource::Runs {
+            job,
+            limit,
+            status,
+            filter,
+        } => {
+            let mut statuses = Vec::new();
+            for word in status {
+                let matching =
+                    run_statuses(word).ok_or_else(|| format!("Unknown status: {}", word))?;
+                statuses.extend(matching.iter().map(|status| status.to_string()));
+            }
+            let search_columns = context.runs_columns();
+            let variables = Variables {
+                pipeline_name: job.clone().unwrap_or_default(),
+                statuses,
+                ..Variables::default()
+            };
+            let runs: Vec<Run> = get_runs(&client, variables, limit.or(context.runs_limit))
+                .await?
+                .into_iter()
+                .map(Run::from)
+                // Match the context's columns, as the TUI's `/` search does, whatever
+                // `-o` prints
+                .filter(|run| {
+                    filter
+                        .as_deref()
+                        .is_none_or(|filter| fuzzy_match(&run_row(run, &search_columns), filter))
+                })
+                .collect();
+            print_runs(&runs, &columns, output)
+        }
+        GetResource::Run { run_id } => {
+            let run = Run::from(get_run(&client, run_id.clone()).await?);
+            match output {
+                OutputFormat::Json => print_json(&run),
+                OutputFormat::Yaml => print_yaml(&run),
+                _ => print_runs(&[run], &columns, output),
+            }
+        }
+        GetResource::Pipelines { filter } => {
+            let pipelines: Vec<Pipeline> = get_pipelines(&client)
+                .await?
+                .into_iter()
+                .filter(|pipeline| {
+                    filter
+                        .as_deref()
+                        .is_none_or(|filter| fuzzy_match(&pipeline_row(pipeline), filter))
+                })
+                .collect();
+            print_pipelines(&pipelines, output)
+        }
+    }
+}
+
+/// Exit status of `d9s wait` when the run failed
+pub const EXIT_RUN_FAILED: i32 = 2;
+/// Exit status of `d9s wait` when the run was canceled
+pub const EXIT_RUN_CANCELED: i32 = 3;
+
+/// Give up on `d9s wait` after this many polls in a row fail
+const MAX_CONSECUTIVE_FAILURES: u32 = 5;
+
+/// Runs `d9s wait`: polls a run until it finishes, printing status changes and optionally
+/// its event log. Returns the process exit status for the run's final status
+pub async fn wait(
+    config: &Config,
+    url: Option<String>,
+    run_id: &str,
+    follow_logs: bool,
+) -> Result<i32, Box<dyn Error>> {
+    let client = connect(config, url)?;
+    let interval = config.polling.interval(Some("run"));
+
+    let mut status = None;
+    let mut log_cursor = None;
+    let mut failures = 0;
+    loop {
+        let polled = async {
+            let run = Run::from(get_run(&client, run_id.to_string()).await?);
+            if follow_logs {
+                print_new_logs(&client, run_id, &mut log_cursor).await?;
+            }
+            Ok::<_, DagsterError>(run)
+        };
+        let run = match polled.await {
+            Ok(run) => run,
+            // A missing run won't show up by retrying
+            Err(e @ DagsterError::RunNotFound(_)) => return Err(e.into()),
+            Err(e) if failures + 1 < MAX_CONSECUTIVE_FAILURES => {
+                failures += 1;
+                eprintln!("d9s: {} (retrying)", e);
+                sleep(interval * 2u32.pow(failures)).await;
+                continue;
+            }
+            Err(e) => return Err(e.into()),
+        };
+        failures = 0;
+
+        if status.as_ref() != Some(&run.status) {
+            println!(
+                "{} {} {}",
+                Utc::now().format(TIME_FORMAT),
+                run.run_id,
+                run.status
+            );
+            status = Some(run.status.clone());
+        }
+
+        // Waiting on a status we can't interpret could block a pipeline forever
+        if !is_known_run_status(&run.status) {
+            return Err(format!(
+                "Run {} has status {}, which d9s doesn't know; can't tell whether it finished",
+                run.run_id, run.status
+            )
+            .into());
+        }
+
+        if is_run_finished(&run.status) {
+            return Ok(match status.as_deref() {
+                Some("SUCCESS") => 0,
+                Some("CANCELED") => EXIT_RUN_CANCELED,
+                _ => EXIT_RUN_FAILED,
+            });
+        }
+
+        sleep(interval).await;
+    }
+}
+
+/// Timestamp format for `d9s wait` output, so CI logs sort and compare easily
+const TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%SZ";
+
+/// Prints the run's events after `cursor`, moving it past every page printed
+async fn print_new_logs(
+    client: &DagsterClient,
+    run_id: &str,
+    cursor: &mut Option<String>,
+) -> Result<(), DagsterError> {
+    loop {
+        let logs = get_run_logs(client, run_id.to_string(), cursor.clone()).await?;
+        for event in logs.events {
+            // Event timestamps are milliseconds since the epoch
+            let time = event
+                .timestamp
+                .parse::<i64>()
+                .ok()
+                .and_then(DateTime::from_timestamp_millis)
+                .map(|time| time.format(TIME_FORMAT).to_string())
+                .unwrap_or(event.timestamp);
+            let step = event
+                .step_key
+                .map(|step| format!(" [{}]", step))
+                .unwrap_or_default();
+            println!("{} {:?}{} {}", time, event.level, step, event.message);
+        }
+        *cursor = Some(logs.cursor);
+        if !logs.has_more {
+            return Ok(());
+        }
+    }
+}
+
+/// Builds a client for the current context, honoring a `--url` override
+fn connect(config: &Config, url: Option<String>) -> Result<DagsterClient, Box<dyn Error>> {
+    let mut context = config.get_current_context();
+    if let Some(url) = url {
+        context = context.with_url(&url)?;
+    }
+    DagsterClient::for_context(&context)
+}
+
+fn print_runs(
+    runs: &[Run],
+    columns: &[RunColumn],
+    output: OutputFormat,
+) -> Result<(), Box<dyn Error>> {
+    match output {
+        OutputFormat::Json => print_json(&runs),
+        OutputFormat::Yaml => print_yaml(&runs),
+        OutputFormat::Table | OutputFormat::Wide => {
+            let header = columns
+                .iter()
+                .map(|column| column.title().to_string())
+                .collect();
+            let rows = runs
+                .iter()
+                .map(|run| {
+                    columns
+                        .iter()
+                        .map(|&column| run_cell(run, column).replace('\n', " "))
+                        .collect()
+                })
+                .collect();
+            print_table(header, rows);
+            Ok(())
+        }
+    }
+}
+
+fn print_pipelines(pipelines: &[Pipeline], output: OutputFormat) -> Result<(), Box<dyn Error>> {
+    match output {
+        OutputFormat::Json => print_json(&pipelines),
+        OutputFormat::Yaml => print_yaml(&pipelines),
+        OutputFormat::Table | OutputFormat::Wide => {
+            let header = ["NAME", "LOCATION", "LAST RUN"]
+                .map(str::to_string)
+                .to_vec();
+            let rows = pipelines
+                .iter()
+                .map(|pipeline| {
+                    vec![
+                        pipeline.name.clone(),
+                        pipeline.repository_location.clone(),
+                        pipeline
+                            .last_run_status
+                            .clone()
+                            .unwrap_or_else(|| "None".to_string()),
+                    ]
+                })
+                .collect();
+            print_table(header, rows);
+            Ok(())
+        }
+    }
+}
+
+fn print_json<T: Serialize>(value: &T) -> Result<(), Box<dyn Error>> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
+fn print_yaml<T: Serialize>(value: &T) -> Result<(), Box<dyn Error>> {
+    print!("{}", serde_yaml::to_string(value)?);
+    Ok(())
+}
+
+/// Prints rows as left-aligned columns, like `kubectl get`
+fn print_table(header: Vec<String>, rows: Vec<Vec<String>>) {
+    let lines: Vec<Vec<String>> = std::iter::once(header).chain(rows).collect();
+    let widths: Vec<usize> = (0..lines[0].len())
+        .map(|i| {
+            lines
+                .iter()
+                .map(|line| line[i].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    for line in lines {
+        let cells: Vec<String> = line
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{:<width$}", cell))
+            .collect();
+        println!("{}", cells.join("   ").trim_end());
+    }
+}
@@ -1,13 +1,16 @@
+use crate::app::BulkAction;
+use crate::command_line::CommandEdit;
+use crate::keys::{Action, Key, KeyScope, Keymap};
 use crate::views::ViewType;
-use crossterm::event::KeyCode;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 
 #[derive(Debug, Clone)]
 pub enum KeyAction {
     Quit,
     NavigateBack,
     ToggleCommandMode,
-    UpdateCommandInput(char),
-    ClearCommandInput,
+    EditCommand(CommandEdit),
+    CompleteCommand,
     ExecuteCommand,
     ToggleSearchMode,
     CommitSearch,
@@ -18,32 +21,111 @@ pub enum KeyAction {
     ScrollDown,
     ScrollLeft,
     ScrollRight,
-    SelectNext(usize),     
-    SelectPrevious(usize), 
-    ViewDetails,          
-    ViewPipelineRuns,    
+    SelectNext(usize),
+    SelectPrevious(usize),
+    ViewDetails,
+    ViewPipelineRuns,
+    CycleSortColumn,
+    ToggleSortOrder,
+    ToggleMark,
+    ToggleVisualMode,
+    CopyRunIds,
+    RequestBulkAction(BulkAction),
+    UpdateConfirmInput(char),
+    ClearConfirmInput,
+    SubmitConfirmation,
+    CancelConfirmation,
+    ToggleErrorPanel,
+    ToggleHelp,
+    Refresh,
+    TogglePause,
     Ignored,
     SwitchContext(String),
     AddContext,
     DeleteContext,
 }
 
-pub fn handle_key(
-    key: KeyCode,
-    view: &ViewType,
-    command_mode: bool,
-    search_mode: bool,
-    selected_index: usize,
-) -> KeyAction {
-    if command_mode {
+impl KeyAction {
+    /// Whether applying this action can change state on the Dagster instance
+    pub fn is_mutation(&self) -> bool {
+        matches!(self, KeyAction::RequestBulkAction(_))
+    }
+}
+
+/// The parts of app state that decide what a key does
+#[derive(Debug, Clone)]
+pub struct InputState {
+    pub view: ViewType,
+    pub command_mode: bool,
+    pub search_mode: bool,
+    pub confirm_mode: bool,
+    pub error_panel: bool,
+    pub help_panel: bool,
+    pub selected_index: usize,
+}
+
+pub fn handle_key(key: KeyEvent, state: &InputState, keymap: &Keymap) -> KeyAction {
+    let typed = Key::from(key).is_typed();
+    let bound = keymap.action(&state.view, Key::from(key));
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    let alt = key.modifiers.contains(KeyModifiers::ALT);
+    let key = key.code;
+
+    if state.confirm_mode {
         match key {
-            KeyCode::Esc => KeyAction::ToggleCommandMode,
-            KeyCode::Char(c) => KeyAction::UpdateCommandInput(c),
-            KeyCode::Backspace => KeyAction::ClearCommandInput,
-            KeyCode::Enter => KeyAction::ExecuteCommand,
+            KeyCode::Esc => KeyAction::CancelConfirmation,
+            KeyCode::Char(c) => KeyAction::UpdateConfirmInput(c),
+            KeyCode::Backspace => KeyAction::ClearConfirmInput,
+            KeyCode::Enter => KeyAction::SubmitConfirmation,
             _ => KeyAction::Ignored,
         }
-    } else if search_mode {
+    } else if state.error_panel {
+        match (bound, key) {
+            (Some((_, Action::Back | Action::Errors | Action::Quit)), _) => {
+                KeyAction::ToggleErrorPanel
+            }
+            (Some((_, Action::Down)), _) | (_, KeyCode::Down) => KeyAction::ScrollDown,
+            (Some((_, Action::Up)), _) | (_, KeyCode::Up) => KeyAction::ScrollUp,
+            _ => KeyAction::Ignored,
+        }
+    } else if state.help_panel {
+        match (bound, key) {
+            (Some((_, Action::Back | Action::Help | Action::Quit)), _) => KeyAction::ToggleHelp,
+            (Some((_, Action::Down)), _) | (_, KeyCode::Down) => KeyAction::ScrollDown,
+            (Some((_, Action::Up)), _) | (_, KeyCode::Up) => KeyAction::ScrollUp,
+            _ => KeyAction::Ignored,
+        }
+    } else if !typed && bound == Some((KeyScope::Global, Action::Refresh)) {
+        // Refresh and help work even while typing a command or search,
+        // as long as they're bound to keys that don't insert text
+        KeyAction::Refresh
+    } else if !typed && bound == Some((KeyScope::Global, Action::Help)) {
+        KeyAction::ToggleHelp
+    } else if state.command_mode {
+        // Readline-style editing
+        let edit = match (key, ctrl, alt) {
+            (KeyCode::Esc, _, _) => return KeyAction::ToggleCommandMode,
+            (KeyCode::Enter, _, _) => return KeyAction::ExecuteCommand,
+            (KeyCode::Tab, _, _) => return KeyAction::CompleteCommand,
+            (KeyCode::Char('w'), true, _) | (KeyCode::Backspace, _, true) => {
+                CommandEdit::DeleteWord
+            }
+            (KeyCode::Char('u'), true, _) => CommandEdit::DeleteToStart,
+            (KeyCode::Char('a'), true, _) | (KeyCode::Home, _, _) => CommandEdit::Home,
+            (KeyCode::Char('e'), true, _) | (KeyCode::End, _, _) => CommandEdit::End,
+            (KeyCode::Char('b'), _, true) | (KeyCode::Left, true, _) => CommandEdit::WordLeft,
+            (KeyCode::Char('f'), _, true) | (KeyCode::Right, true, _) => CommandEdit::WordRight,
+            (KeyCode::Char('p'), true, _) | (KeyCode::Up, _, _) => CommandEdit::HistoryPrevious,
+            (KeyCode::Char('n'), true, _) | (KeyCode::Down, _, _) => CommandEdit::HistoryNext,
+            (KeyCode::Left, _, _) => CommandEdit::Left,
+            (KeyCode::Right, _, _) => CommandEdit::Right,
+            (KeyCode::Backspace, _, _) => CommandEdit::Backspace,
+            (KeyCode::Delete, _, _) => CommandEdit::Delete,
+            (KeyCode::Char(c), false, false) => CommandEdit::Insert(c),
+            _ => return KeyAction::Ignored,
+        };
+        KeyAction::EditCommand(edit)
+    } else if state.search_mode {
         match key {
             KeyCode::Esc => KeyAction::CancelSearch,
             KeyCode::Char(c) => KeyAction::UpdateSearchInput(c),
@@ -52,76 +134,58 @@ pub fn handle_key(
             _ => KeyAction::Ignored,
         }
     } else {
-        match key {
-            KeyCode::Char('q') => KeyAction::Quit,
-            KeyCode::Char(':') => KeyAction::ToggleCommandMode,
-            // Only allow search toggle in searchable views
-            KeyCode::Char('/') => match view {
-                ViewType::Runs | ViewType::PipelineRuns(_) | ViewType::Pipelines => {
-                    KeyAction::ToggleSearchMode
-                }
-                // Ignore search for other views
-                _ => KeyAction::Ignored,
-            },
-            KeyCode::Esc => KeyAction::NavigateBack,
-            _ => match view {
-                ViewType::Run(_) => handle_run_view_key(key, selected_index),
-                ViewType::Runs => handle_runs_view_key(key, selected_index),
-                ViewType::Contexts => handle_contexts_view_key(key, selected_index),
-                ViewType::Pipelines => handle_pipelines_view_key(key, selected_index),
-                ViewType::PipelineRuns(_) => handle_runs_view_key(key, selected_index),
-                ViewType::Default => handle_default_view_key(key),
-            },
+        match bound {
+            Some((_, action)) => bound_action(action, &state.view, state.selected_index),
+            None => KeyAction::Ignored,
         }
     }
 }
-fn handle_run_view_key(key: KeyCode, _selected_index: usize) -> KeyAction {
-    match key {
-        KeyCode::Char('j') | KeyCode::Down => KeyAction::ScrollDown,
-        KeyCode::Char('k') | KeyCode::Up => KeyAction::ScrollUp,
-        KeyCode::Char('h') | KeyCode::Left => KeyAction::ScrollLeft,
-        KeyCode::Char('l') | KeyCode::Right => KeyAction::ScrollRight,
-        _ => KeyAction::Ignored,
-    }
-}
-
-fn handle_runs_view_key(key: KeyCode, selected_index: usize) -> KeyAction {
-    match key {
-        KeyCode::Char('j') | KeyCode::Down => KeyAction::SelectNext(0), // viewport_height will be filled later
-        KeyCode::Char('k') | KeyCode::Up => KeyAction::SelectPrevious(0), // viewport_height will be filled later
-        KeyCode::Enter if selected_index >= 2 => {
-            // We'll get the run ID from the selected item in apply_key_action
-            KeyAction::ViewDetails
-        }
-        _ => KeyAction::Ignored,
-    }
-}
-
-fn handle_default_view_key(key: KeyCode) -> KeyAction {
-    match key {
-        _ => KeyAction::Ignored,
-    }
-}
-
-fn handle_contexts_view_key(key: KeyCode, selected_index: usize) -> KeyAction {
-    match key {
-        KeyCode::Char('j') | KeyCode::Down => KeyAction::SelectNext(0),
-        KeyCode::Char('k') | KeyCode::Up => KeyAction::SelectPrevious(0),
-        KeyCode::Enter if selected_index >= 2 => KeyAction::SwitchContext(String::new()),
-        KeyCode::Char('a') => KeyAction::AddContext,
-        KeyCode::Char('d') if selected_index >= 2 => KeyAction::DeleteContext,
-        _ => KeyAction::Ignored,
-    }
-}
 
-fn handle_pipelines_view_key(key: KeyCode, selected_index: usize) -> KeyAction {
-    match key {
-        KeyCode::Char('j') | KeyCode::Down => KeyAction::SelectNext(0), // viewport_height will be filled later
-        KeyCode::Char('k') | KeyCode::Up => KeyAction::SelectPrevious(0), // viewport_height will be filled later
-        KeyCode::Enter if selected_index >= 2 => {
-            // We'll get the pipeline name later since we don't have access to items here
-            KeyAction::ViewPipelineRuns
-        }
-        _ => KeyAction::Ignored,
+/// What a bound action does in a view; `Keymap` only allows actions the view has
+fn bound_action(action: Action, view: &ViewType, selected_index: usize) -> KeyAction {
+    // The first two rows of lists are headers
+    let on_item = selected_index >= 2;
+    match action {
+        Action::Quit => KeyAction::Quit,
+        Action::Command => KeyAction::ToggleCommandMode,
+        // Only allow search toggle in searchable views
+        Action::Search if view.is_searchable() => KeyAction::ToggleSearchMode,
+        Action::Search => KeyAction::Ignored,
+        Action::Back => KeyAction::NavigateBack,
+        Action::Errors => KeyAction::ToggleErrorPanel,
+        Action::Refresh => KeyAction::Refresh,
+        Action::Pause => KeyAction::TogglePause,
+        Action::Help => KeyAction::ToggleHelp,
+        // viewport_height is filled in by apply_key_action
+        Action::Down => match view {
+            ViewType::Run(_) => KeyAction::ScrollDown,
+            _ => KeyAction::SelectNext(0),
+        },
+        Action::Up => match view {
+            ViewType::Run(_) => KeyAction::ScrollUp,
+            _ => KeyAction::SelectPrevious(0),
+        },
+        Action::Left => KeyAction::ScrollLeft,
+        Action::Right => KeyAction::ScrollRight,
+        // The selected run, pipeline or context is looked up in apply_key_action
+        Action::Open if !on_item => KeyAction::Ignored,
+        Action::Open => match view {
+            ViewType::Pipelines => KeyAction::ViewPipelineRuns,
+            ViewType::Contexts => KeyAction::SwitchContext(String::new()),
+            _ => KeyAction::ViewDetails,
+        },
+        Action::Mark => KeyAction::ToggleMark,
+        Action::Visual => KeyAction::ToggleVisualMode,
+        Action::Copy => KeyAction::CopyRunIds,
+        Action::Terminate => KeyAction::RequestBulkAction(BulkAction::Terminate),
+        Action::Reexecute => KeyAction::RequestBulkAction(BulkAction::ReexecuteFromFailure),
+        Action::Delete => match view {
+            ViewType::Contexts if on_item => KeyAction::DeleteContext,
+            ViewType::Contexts => KeyAction::Ignored,
+            _ => KeyAction::RequestBulkAction(BulkAction::Delete),
+        },
+        Action::Add => KeyAction::AddContext,
+        Action::SortColumn => KeyAction::CycleSortColumn,
+        Action::SortOrder => KeyAction::ToggleSortOrder,
     }
 }
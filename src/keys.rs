@@ -0,0 +1,452 @@
+use crate::views::ViewType;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// The `[keys]` tables in config.toml: per scope, the keys for each remapped action
+pub type KeysConfig = HashMap<KeyScope, HashMap<Action, KeyList>>;
+
+/// One key or a list of keys, e.g. `copy = "y"` or `down = ["Down", "j"]`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum KeyList {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl KeyList {
+    fn names(&self) -> &[String] {
+        match self {
+            KeyList::One(name) => std::slice::from_ref(name),
+            KeyList::Many(names) => names,
+        }
+    }
+}
+
+/// Something a key can be bound to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Action {
+    Quit,
+    Command,
+    Search,
+    Back,
+    Errors,
+    Refresh,
+    Pause,
+    Help,
+    Down,
+    Up,
+    Left,
+    Right,
+    Open,
+    Mark,
+    Visual,
+    Copy,
+    Terminate,
+    Reexecute,
+    Delete,
+    Add,
+    SortColumn,
+    SortOrder,
+}
+
+impl Action {
+    pub fn description(&self) -> &'static str {
+        match self {
+            Action::Quit => "quit",
+            Action::Command => "enter command mode",
+            Action::Search => "search the current list",
+            Action::Back => "go back",
+            Action::Errors => "show the last error",
+            Action::Refresh => "refresh now",
+            Action::Pause => "pause or resume polling",
+            Action::Help => "show keys and commands for this view",
+            Action::Down => "move down",
+            Action::Up => "move up",
+            Action::Left => "scroll left",
+            Action::Right => "scroll right",
+            Action::Open => "open the selection",
+            Action::Mark => "mark the run",
+            Action::Visual => "mark a range of runs",
+            Action::Copy => "copy run IDs",
+            Action::Terminate => "terminate runs",
+            Action::Reexecute => "re-execute runs from failure",
+            Action::Delete => "delete",
+            Action::Add => "add a context",
+            Action::SortColumn => "change the sort column",
+            Action::SortOrder => "reverse the sort order",
+        }
+    }
+
+    fn name(&self) -> String {
+        serde_json::to_value(self)
+            .ok()
+            .and_then(|value| value.as_str().map(str::to_string))
+            .unwrap_or_default()
+    }
+}
+
+/// Where a binding applies: everywhere, or in one view
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum KeyScope {
+    Global,
+    Runs,
+    Run,
+    Pipelines,
+    Contexts,
+    Debuglog,
+}
+
+impl KeyScope {
+    pub const ALL: [KeyScope; 6] = [
+        KeyScope::Global,
+        KeyScope::Runs,
+        KeyScope::Run,
+        KeyScope::Pipelines,
+        KeyScope::Contexts,
+        KeyScope::Debuglog,
+    ];
+
+    /// The view's own scope, or None for views with only global keys
+    pub fn of(view: &ViewType) -> Option<KeyScope> {
+        match view {
+            ViewType::Runs | ViewType::PipelineRuns(_) => Some(KeyScope::Runs),
+            ViewType::Run(_) => Some(KeyScope::Run),
+            ViewType::Pipelines => Some(KeyScope::Pipelines),
+            ViewType::Contexts => Some(KeyScope::Contexts),
+            ViewType::DebugLog => Some(KeyScope::Debuglog),
+            ViewType::Default => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            KeyScope::Global => "global",
+            KeyScope::Runs => "runs",
+            KeyScope::Run => "run",
+            KeyScope::Pipelines => "pipelines",
+            KeyScope::Contexts => "contexts",
+            KeyScope::Debuglog => "debuglog",
+        }
+    }
+
+    /// The actions available in this scope, with their default keys
+    fn defaults(&self) -> &'static [(Action, &'static [&'static str])] {
+        const UP: (Action, &[&str]) = (Action::Up, &["Up", "k"]);
+        const DOWN: (Action, &[&str]) = (Action::Down, &["Down", "j"]);
+        match self {
+            KeyScope::Global => &[
+                (Action::Quit, &["q"]),
+                (Action::Command, &[":"]),
+                (Action::Search, &["/"]),
+                (Action::Back, &["Esc"]),
+                (Action::Errors, &["e"]),
+                (Action::Refresh, &["r", "Ctrl-r"]),
+                (Action::Pause, &["p"]),
+                (Action::Help, &["?", "F1"]),
+            ],
+            KeyScope::Runs => &[
+                UP,
+                DOWN,
+                (Action::Open, &["Enter"]),
+                (Action::Mark, &["Space"]),
+                (Action::Visual, &["V"]),
+                (Action::Copy, &["y"]),
+                (Action::Terminate, &["t"]),
+                (Action::Reexecute, &["R"]),
+                (Action::Delete, &["D"]),
+                (Action::SortColumn, &["S"]),
+                (Action::SortOrder, &["s"]),
+            ],
+            KeyScope::Run => &[
+                UP,
+                DOWN,
+                (Action::Left, &["Left", "h"]),
+                (Action::Right, &["Right", "l"]),
+                (Action::Delete, &["D"]),
+            ],
+            KeyScope::Pipelines => &[
+                UP,
+                DOWN,
+                (Action::Open, &["Enter"]),
+                (Action::SortColumn, &["S"]),
+                (Action::SortOrder, &["s"]),
+            ],
+            KeyScope::Contexts => &[
+                UP,
+                DOWN,
+                (Action::Open, &["Enter"]),
+                (Action::Add, &["a"]),
+                (Action::Delete, &["d"]),
+            ],
+            KeyScope::Debuglog => &[UP, DOWN],
+        }
+    }
+}
+
+/// A key press, ignoring Shift since it's already reflected in the character
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Key {
+    pub code: KeyCode,
+    pub ctrl: bool,
+}
+
+impl Key {
+    /// Whether the key inserts text in the command bar or search
+    pub fn is_typed(&self) -> bool {
+        matches!(self.code, KeyCode::Char(_)) && !self.ctrl
+    }
+}
+
+impl From<KeyEvent> for Key {
+    fn from(event: KeyEvent) -> Self {
+        Self {
+            code: event.code,
+            ctrl: event.modifiers.contains(KeyModifiers::CONTROL),
+        }
+    }
+}
+
+impl FromStr for Key {
+    type Err = String;
+
+    /// Parses a single character, a key name such as `Enter` or `PageDown`,
+    /// or either of those prefixed with `Ctrl-`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (ctrl, name) = match s.get(..5) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("ctrl-") && s.len() > 5 => (true, &s[5..]),
+            _ => (false, s),
+        };
+
+        let mut chars = name.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) => KeyCode::Char(if ctrl { c.to_ascii_lowercase() } else { c }),
+            _ => match name.to_lowercase().as_str() {
+                "enter" => KeyCode::Enter,
+                "esc" | "escape" => KeyCode::Esc,
+                "space" => KeyCode::Char(' '),
+                "tab" => KeyCode::Tab,
+                "backspace" => KeyCode::Backspace,
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pageup" => KeyCode::PageUp,
+                "pagedown" => KeyCode::PageDown,
+                "delete" => KeyCode::Delete,
+                function => match function.strip_prefix('f').map(str::parse) {
+                    Some(Ok(n)) if (1..=12).contains(&n) => KeyCode::F(n),
+                    _ => return Err(format!("unknown key `{}`", s)),
+                },
+            },
+        };
+
+        Ok(Self { code, ctrl })
+    }
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.ctrl {
+            write!(f, "Ctrl-")?;
+        }
+        match self.code {
+            KeyCode::Char(' ') => write!(f, "Space"),
+            KeyCode::Char(c) => write!(f, "{}", c),
+            KeyCode::Up => write!(f, "↑"),
+            KeyCode::Down => write!(f, "↓"),
+            KeyCode::Left => write!(f, "←"),
+            KeyCode::Right => write!(f, "→"),
+            KeyCode::Esc => write!(f, "Esc"),
+            KeyCode::PageUp => write!(f, "PageUp"),
+            KeyCode::PageDown => write!(f, "PageDown"),
+            code => write!(f, "{}", code),
+        }
+    }
+}
+
+/// The active key bindings: the defaults with the `[keys]` config applied
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: HashMap<KeyScope, Vec<(Action, Vec<Key>)>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::new(&KeysConfig::new()).expect("default key bindings are valid")
+    }
+}
+
+impl Keymap {
+    /// Apply `config` over the default bindings, rejecting unknown keys, actions
+    /// a view doesn't have, and keys bound to two actions in the same view
+    pub fn new(config: &KeysConfig) -> Result<Self, String> {
+        let mut errors = Vec::new();
+        let mut bindings = HashMap::new();
+
+        for scope in KeyScope::ALL {
+            let overrides = config.get(&scope);
+            if let Some(overrides) = overrides {
+                for action in overrides.keys() {
+                    if !scope.defaults().iter().any(|(a, _)| a == action) {
+                        errors.push(format!(
+                            "keys.{}: there is no `{}` action here",
+                            scope.name(),
+                            action.name()
+                        ));
+                    }
+                }
+            }
+
+            let mut scope_bindings = Vec::new();
+            for (action, default_keys) in scope.defaults() {
+                let names: Vec<&str> = match overrides.and_then(|o| o.get(action)) {
+                    Some(list) => list.names().iter().map(String::as_str).collect(),
+                    None => default_keys.to_vec(),
+                };
+                let mut keys = Vec::new();
+                for name in names {
+                    match name.parse() {
+                        Ok(key) => keys.push(key),
+                        Err(e) => {
+                            errors.push(format!("keys.{}.{}: {}", scope.name(), action.name(), e))
+                        }
+                    }
+                }
+                scope_bindings.push((*action, keys));
+            }
+            bindings.insert(scope, scope_bindings);
+        }
+
+        let keymap = Self { bindings };
+        errors.extend(keymap.conflicts());
+        if errors.is_empty() {
+            Ok(keymap)
+        } else {
+            Err(errors.join("\n"))
+        }
+    }
+
+    /// Keys bound to more than one action in the same view, counting global keys
+    fn conflicts(&self) -> Vec<String> {
+        let mut conflicts = Vec::new();
+        let mut global: HashMap<Key, (KeyScope, Action)> = HashMap::new();
+        Self::check(
+            KeyScope::Global,
+            &self.bindings[&KeyScope::Global],
+            &mut global,
+            &mut conflicts,
+        );
+
+        for scope in KeyScope::ALL.into_iter().filter(|s| *s != KeyScope::Global) {
+            Self::check(
+                scope,
+                &self.bindings[&scope],
+                &mut global.clone(),
+                &mut conflicts,
+            );
+        }
+        conflicts
+    }
+
+    fn check(
+        scope: KeyScope,
+        bindings: &[(Action, Vec<Key>)],
+        seen: &mut HashMap<Key, (KeyScope, Action)>,
+        conflicts: &mut Vec<String>,
+    ) {
+        for (action, keys) in bindings {
+            for key in keys {
+                match seen.get(key) {
+                    Some(&(other_scope, other)) if other_scope != scope || other != *action => {
+                        conflicts.push(format!(
+                            "`{}` is bound to both {} ({}) and {} ({})",
+                            key,
+                            other.name(),
+                            other_scope.name(),
+                            action.name(),
+                            scope.name()
+                        ));
+                    }
+                    _ => {
+                        seen.insert(*key, (scope, *action));
+                    }
+                }
+            }
+        }
+    }
+
+    /// The action `key` triggers in `view`
+    pub fn action(&self, view: &ViewType, key: Key) -> Option<(KeyScope, Action)> {
+        std::iter::once(KeyScope::Global)
+            .chain(KeyScope::of(view))
+            .find_map(|scope| {
+                self.bindings[&scope]
+                    .iter()
+                    .find(|(_, keys)| keys.contains(&key))
+                    .map(|(action, _)| (scope, *action))
+            })
+    }
+
+    /// The keys bound to an action in `view`, including global keys
+    pub fn keys(&self, view: &ViewType, action: Action) -> Vec<Key> {
+        std::iter::once(KeyScope::Global)
+            .chain(KeyScope::of(view))
+            .flat_map(|scope| &self.bindings[&scope])
+            .filter(|(a, _)| *a == action)
+            .flat_map(|(_, keys)| keys.iter().copied())
+            .collect()
+    }
+
+    /// The actions in a scope with their keys, in a stable order
+    pub fn bindings(&self, scope: KeyScope) -> &[(Action, Vec<Key>)] {
+        &self.bindings[&scope]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keymap_overrides_and_conflicts() {
+        let config: KeysConfig = toml::from_str(
+            r#"
+            [runs]
+            copy = ["c", "Ctrl-y"]
+            "#,
+        )
+        .unwrap();
+        let keymap = Keymap::new(&config).unwrap();
+        let ctrl_y = Key {
+            code: KeyCode::Char('y'),
+            ctrl: true,
+        };
+        assert_eq!(
+            keymap.action(&ViewType::Runs, ctrl_y),
+            Some((KeyScope::Runs, Action::Copy))
+        );
+        assert_eq!(keymap.action(&ViewType::Runs, "y".parse().unwrap()), None);
+        assert_eq!(
+            keymap.keys(&ViewType::Runs, Action::Down),
+            vec!["Down".parse().unwrap(), "j".parse::<Key>().unwrap()]
+        );
+
+        // `e` already opens the error panel everywhere
+        let config: KeysConfig = toml::from_str("[runs]\ncopy = \"e\"").unwrap();
+        let error = Keymap::new(&config).unwrap_err();
+        assert_eq!(
+            error,
+            "`e` is bound to both errors (global) and copy (runs)"
+        );
+
+        let config: KeysConfig = toml::from_str("[pipelines]\nterminate = \"t\"").unwrap();
+        assert!(Keymap::new(&config).is_err());
+    }
+}
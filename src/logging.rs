@@ -0,0 +1,187 @@
+use crate::config::LoggingConfig;
+use chrono::{DateTime, Local};
+use env_logger::Builder;
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// How many records `:debuglog` can show
+const RECENT_CAPACITY: usize = 1000;
+
+/// d9s's own most recent log records, oldest first
+static RECENT: Mutex<VecDeque<LogRecord>> = Mutex::new(VecDeque::new());
+
+/// A log record kept in memory for the `:debuglog` view
+#[derive(Debug, Clone)]
+pub struct LogRecord {
+    pub time: DateTime<Local>,
+    pub level: Level,
+    /// Module that logged the record, without the `d9s::` prefix
+    pub target: String,
+    pub message: String,
+}
+
+/// The most recent records, newest first
+pub fn recent_records() -> Vec<LogRecord> {
+    let recent = RECENT.lock().unwrap_or_else(|e| e.into_inner());
+    recent.iter().rev().cloned().collect()
+}
+
+/// Sets up logging to `path` at `level`, rotating the file as configured
+pub fn setup_logging(
+    path: &Path,
+    level: LevelFilter,
+    config: &LoggingConfig,
+) -> Result<(), Box<dyn Error>> {
+    let max_bytes = config.max_size_mb.saturating_mul(1024 * 1024);
+    let log_file = RotatingFile::open(path.to_path_buf(), max_bytes, config.max_files)
+        .map_err(|e| format!("Can't open log file {}: {}", path.display(), e))?;
+    let file = Builder::new()
+        .target(env_logger::Target::Pipe(Box::new(log_file)))
+        .filter_level(level)
+        .build();
+
+    log::set_boxed_logger(Box::new(D9sLogger { file }))?;
+    log::set_max_level(level.max(LevelFilter::Debug));
+
+    Ok(())
+}
+
+/// Writes records to the log file, and also keeps d9s's own debug records in
+/// memory so they can be read without leaving the TUI, whatever the file's level
+struct D9sLogger {
+    file: env_logger::Logger,
+}
+
+impl D9sLogger {
+    fn is_recent(metadata: &Metadata) -> bool {
+        metadata.level() <= Level::Debug && metadata.target().starts_with("d9s")
+    }
+}
+
+impl Log for D9sLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.file.enabled(metadata) || Self::is_recent(metadata)
+    }
+
+    fn log(&self, record: &Record) {
+        if self.file.matches(record) {
+            self.file.log(record);
+        }
+
+        if Self::is_recent(record.metadata()) {
+            let target = record.target().trim_start_matches("d9s::");
+            let mut recent = RECENT.lock().unwrap_or_else(|e| e.into_inner());
+            if recent.len() == RECENT_CAPACITY {
+                recent.pop_front();
+            }
+            recent.push_back(LogRecord {
+                time: Local::now(),
+                level: record.level(),
+                target: if target == "d9s" { "main" } else { target }.to_string(),
+                message: record.args().to_string(),
+            });
+        }
+    }
+
+    fn flush(&self) {
+        self.file.flush();
+    }
+}
+
+/// A log file that is appended to across sessions, and moved to `<path>.1`
+/// (shifting older logs up to `<path>.<max_files>`) once it reaches `max_bytes`
+struct RotatingFile {
+    path: PathBuf,
+    max_bytes: u64,
+    max_files: usize,
+    file: File,
+    size: u64,
+}
+
+impl RotatingFile {
+    fn open(path: PathBuf, max_bytes: u64, max_files: usize) -> io::Result<Self> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let size = file.metadata()?.len();
+        Ok(Self {
+            path,
+            max_bytes,
+            max_files,
+            file,
+            size,
+        })
+    }
+
+    fn rotated_path(&self, index: usize) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(format!(".{}", index));
+        path.into()
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        if self.max_files > 0 {
+            for index in (1..self.max_files).rev() {
+                let from = self.rotated_path(index);
+                if from.exists() {
+                    fs::rename(from, self.rotated_path(index + 1))?;
+                }
+            }
+            fs::rename(&self.path, self.rotated_path(1))?;
+        }
+        self.file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .truncate(true)
+            .open(&self.path)?;
+        self.size = 0;
+        Ok(())
+    }
+}
+
+impl Write for RotatingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // A max size of 0 turns rotation off
+        if self.max_bytes > 0 && self.size > 0 && self.size + buf.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+        let written = self.file.write(buf)?;
+        self.size += written as u64;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotating_file() {
+        let dir = std::env::temp_dir().join(format!("d9s-log-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let path = dir.join("d9s.log");
+
+        let mut file = RotatingFile::open(path.clone(), 10, 2).unwrap();
+        for line in ["first\n", "second\n", "third\n", "fourth\n"] {
+            file.write_all(line.as_bytes()).unwrap();
+        }
+
+        let read = |path: PathBuf| fs::read_to_string(path).unwrap();
+        assert_eq!(read(path.clone()), "fourth\n");
+        assert_eq!(read(file.rotated_path(1)), "third\n");
+        assert_eq!(read(file.rotated_path(2)), "second\n");
+        assert!(!file.rotated_path(3).exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
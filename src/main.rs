@@ -1,50 +1,128 @@
 use crossterm::{
-    event::{self, Event},
+    event::{self, DisableFocusChange, EnableFocusChange, Event},
     execute,
     terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
 };
-use env_logger::Builder;
-use log::LevelFilter;
 use ratatui::prelude::*;
-use std::{error::Error, io, time::Duration};
+use std::{
+    error::Error,
+    io,
+    time::{Duration, Instant},
+};
 use tokio::time::sleep;
 
+mod aliases;
 mod app;
+mod cli;
+mod client;
+mod command_line;
 mod config;
+mod delete_run;
+mod error;
 mod get_pipelines;
 mod get_run;
+mod get_run_logs;
 mod get_runs;
+mod headless;
 mod input;
+mod keys;
+mod logging;
+mod reexecute_run;
 mod search;
+mod session;
+mod terminate_run;
+mod theme;
 mod ui;
 mod utils;
 mod views;
 
-use crate::input::{KeyAction, handle_key};
+use crate::cli::{Cli, Command};
+use crate::config::state_dir;
+use crate::input::{InputState, KeyAction, handle_key};
+use crate::logging::setup_logging;
 use app::App;
+use clap::Parser;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
 // How often to refresh the UI when there are no input events
 const UI_REFRESH_INTERVAL: Duration = Duration::from_millis(100);
+// How often to check the config file for edits
+const CONFIG_CHECK_INTERVAL: Duration = Duration::from_secs(1);
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
-    // Set up terminal
-    enable_raw_mode()?;
-    let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen)?;
+    let cli = Cli::parse();
+
+    // Config problems are reported before the terminal switches to the TUI
+    let config = match cli.load_config() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("d9s: {}", e);
+            std::process::exit(1);
+        }
+    };
 
     // Configure logging to file
-    setup_logging()?;
+    let log_file = cli
+        .log_file
+        .clone()
+        .unwrap_or_else(|| config.logging.path());
+    let log_level = cli.log_level.unwrap_or(config.logging.level);
+    setup_logging(&log_file, log_level, &config.logging)?;
     log::info!("Starting application");
 
+    if let Some(Command::Get { resource, output }) = &cli.command {
+        if let Err(e) = headless::get(&config, cli.url.clone(), resource, *output).await {
+            eprintln!("d9s: {}", e);
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+    if let Some(Command::Wait {
+        run_id,
+        follow_logs,
+    }) = &cli.command
+    {
+        match headless::wait(&config, cli.url.clone(), run_id, *follow_logs).await {
+            Ok(code) => std::process::exit(code),
+            Err(e) => {
+                eprintln!("d9s: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    let mut app = App::new(config, Some(&state_dir()));
+    if let Some(url) = &cli.url
+        && let Err(e) = app.set_url(url)
+    {
+        eprintln!("d9s: --url: {}", e);
+        std::process::exit(1);
+    }
+    app.force_read_only = cli.readonly;
+
+    // Set up terminal
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen, EnableFocusChange)?;
+
     // Initialize terminal backend
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
+    // A view asked for on the command line wins over the last session's
+    let last_view = app.restore_session();
+    if let Some(view) = cli.start_view() {
+        app.open_start_view(view).await;
+    } else if let Some(view) = last_view
+        && let Err(e) = app.open_view(view).await
+    {
+        log::warn!("Failed to reopen the last view: {}", e);
+    }
+
     // Initialize app state
-    let app = Arc::new(Mutex::new(App::new()));
+    let app = Arc::new(Mutex::new(app));
     let app_clone = app.clone();
 
     // Spawn background polling task
@@ -53,11 +131,16 @@ async fn main() -> Result<(), Box<dyn Error>> {
     });
 
     // Run the main application loop
-    let res = run_app(&mut terminal, app).await;
+    let res = run_app(&mut terminal, app.clone()).await;
+    app.lock().await.save_session();
 
     // Clean up terminal before exit
     disable_raw_mode()?;
-    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    execute!(
+        terminal.backend_mut(),
+        DisableFocusChange,
+        LeaveAlternateScreen
+    )?;
     terminal.show_cursor()?;
 
     // Report any errors that occurred
@@ -69,30 +152,25 @@ async fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-/// Sets up logging to write to a file with debug level
-fn setup_logging() -> Result<(), Box<dyn Error>> {
-    let log_file = std::fs::File::create("debug.log")?;
-    Builder::new()
-        .target(env_logger::Target::Pipe(Box::new(log_file)))
-        .filter_level(LevelFilter::Debug)
-        .init();
-    
-    Ok(())
-}
-
 /// Main application loop
 async fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: Arc<Mutex<App>>) -> io::Result<()> {
+    let mut config_checked = Instant::now();
     loop {
+        if config_checked.elapsed() >= CONFIG_CHECK_INTERVAL {
+            config_checked = Instant::now();
+            app.lock().await.reload_config_if_changed();
+        }
+
         // Handle pending view transitions
         {
             let mut app_guard = app.lock().await;
-            if let Some((next_view, reset_history)) = app_guard.next_view.take() {
-                if let Err(e) = app_guard.enter_view(next_view, reset_history).await {
-                    log::error!("Failed to enter view: {:?}", e);
-                }
+            if let Some((next_view, reset_history)) = app_guard.next_view.take()
+                && let Err(e) = app_guard.enter_view(next_view, reset_history).await
+            {
+                log::error!("Failed to enter view: {:?}", e);
             }
         }
-        
+
         // Draw the UI
         {
             let app_guard = app.lock().await;
@@ -101,21 +179,30 @@ async fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: Arc<Mutex<App>>) -
 
         // Handle input events with timeout
         if event::poll(UI_REFRESH_INTERVAL)? {
-            if let Event::Key(key) = event::read()? {
+            let event = event::read()?;
+            if let Event::FocusGained | Event::FocusLost = event {
+                app.lock().await.focused = matches!(event, Event::FocusGained);
+            } else if let Event::Key(key) = event {
                 // Get minimal app state without holding a long lock
-                let (view, command_mode, search_mode, selected_index, viewport_height) = {
+                let (state, keymap, viewport_height) = {
                     let app_guard = app.lock().await;
                     (
-                        app_guard.view.clone(),
-                        app_guard.command_mode,
-                        app_guard.search_mode,
-                        app_guard.selected_index,
+                        InputState {
+                            view: app_guard.view.clone(),
+                            command_mode: app_guard.command_mode,
+                            search_mode: app_guard.search_mode,
+                            confirm_mode: app_guard.confirmation.is_some(),
+                            error_panel: app_guard.show_error,
+                            help_panel: app_guard.help.is_some(),
+                            selected_index: app_guard.selected_index,
+                        },
+                        app_guard.keymap.clone(),
                         (terminal.size()?.height as usize).saturating_sub(3),
                     )
                 };
 
                 // Process the key with the current state
-                let action = handle_key(key.code, &view, command_mode, search_mode, selected_index);
+                let action = handle_key(key, &state, &keymap);
 
                 // Apply the action with a fresh lock
                 match action {
@@ -125,6 +212,12 @@ async fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: Arc<Mutex<App>>) -
                         if let Err(e) = app_guard.apply_key_action(action, viewport_height).await {
                             log::error!("Error applying key action: {:?}", e);
                         }
+                        if let Some(pending) = app_guard.pending_bulk_action.take() {
+                            tokio::spawn(App::run_bulk_action(app.clone(), pending));
+                        }
+                        if app_guard.quit_requested {
+                            return Ok(());
+                        }
                     }
                 }
             }
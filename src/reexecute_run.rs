@@ -0,0 +1,46 @@
+use crate::client::DagsterClient;
+use crate::error::DagsterError;
+use graphql_client::GraphQLQuery;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "queries/schema.json",
+    query_path = "queries/reexecute_run.graphql",
+    response_derives = "Debug,Clone"
+)]
+pub struct ReexecuteRunMutation;
+
+/// Launch a new run re-executing the failed steps of a run. Returns the ID of the new run.
+pub async fn reexecute_run_from_failure(
+    client: &DagsterClient,
+    parent_run_id: String,
+) -> Result<String, DagsterError> {
+    let query_variables = reexecute_run_mutation::Variables {
+        parent_run_id,
+        strategy: reexecute_run_mutation::ReexecutionStrategy::FROM_FAILURE,
+    };
+    let data = client
+        .execute::<ReexecuteRunMutation>(query_variables)
+        .await?;
+
+    use reexecute_run_mutation::ReexecuteRunMutationLaunchRunReexecution as LaunchResult;
+    let message = match data.launch_run_reexecution {
+        LaunchResult::LaunchRunSuccess(success) => return Ok(success.run.run_id),
+        LaunchResult::RunConflict(err) => err.message,
+        LaunchResult::PipelineNotFoundError(err) => err.message,
+        LaunchResult::InvalidSubsetError(err) => err.message,
+        LaunchResult::ConflictingExecutionParamsError(err) => err.message,
+        LaunchResult::NoModeProvidedError(err) => err.message,
+        LaunchResult::PresetNotFoundError(err) => err.message,
+        LaunchResult::UnauthorizedError(err) => err.message,
+        LaunchResult::PythonError(err) => {
+            return Err(DagsterError::Python {
+                message: err.message,
+                stack: err.stack,
+            });
+        }
+        other => format!("Re-execution rejected: {:?}", other),
+    };
+
+    Err(DagsterError::Dagster(message))
+}
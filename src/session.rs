@@ -0,0 +1,101 @@
+use crate::app::ViewState;
+use crate::config::SavedView;
+use crate::views::ViewType;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// What was on screen in each context when d9s last exited
+///
+/// Kept in the state directory rather than `config.toml`, since it changes on every exit.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Session {
+    #[serde(default)]
+    pub contexts: HashMap<String, ContextSession>,
+    /// Where the session is saved
+    #[serde(skip)]
+    path: Option<PathBuf>,
+}
+
+/// The last view of a context, and the selection and scroll position of each list visited
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContextSession {
+    pub view: SavedView,
+    #[serde(default)]
+    pub view_states: Vec<(ViewType, ViewState)>,
+}
+
+impl Session {
+    /// Load the session saved at `path`, starting afresh if there isn't a usable one
+    pub fn load(path: PathBuf) -> Self {
+        let session = match fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents).unwrap_or_else(|e| {
+                log::warn!("Ignoring invalid session {}: {}", path.display(), e);
+                Session::default()
+            }),
+            Err(_) => Session::default(),
+        };
+
+        Self {
+            path: Some(path),
+            ..session
+        }
+    }
+
+    pub fn save(&self) {
+        let Some(path) = &self.path else {
+            return;
+        };
+
+        let result = serde_json::to_string_pretty(self)
+            .map_err(std::io::Error::other)
+            .and_then(|contents| {
+                path.parent()
+                    .map_or(Ok(()), fs::create_dir_all)
+                    .and_then(|_| fs::write(path, contents))
+            });
+        if let Err(e) = result {
+            log::warn!("Failed to save session to {}: {}", path.display(), e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::{PipelineColumn, SortColumn, SortState};
+
+    #[test]
+    fn test_session_round_trip() {
+        let path = std::env::temp_dir().join(format!("d9s-session-{}.json", std::process::id()));
+        let mut session = Session::load(path.clone());
+        assert!(session.contexts.is_empty());
+
+        let prod = ContextSession {
+            view: SavedView {
+                context: "prod".to_string(),
+                view: ViewType::PipelineRuns("nightly_etl".to_string()),
+                statuses: vec!["FAILURE".to_string()],
+                search: "backfill".to_string(),
+                sort: None,
+            },
+            view_states: vec![(
+                ViewType::Pipelines,
+                ViewState {
+                    selected_index: 7,
+                    list_offset: 3,
+                    sort: Some(SortState {
+                        column: SortColumn::Pipeline(PipelineColumn::RepositoryLocation),
+                        descending: true,
+                    }),
+                },
+            )],
+        };
+        session.contexts.insert("prod".to_string(), prod.clone());
+        session.save();
+
+        assert_eq!(Session::load(path.clone()).contexts["prod"], prod);
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,31 @@
+use crate::client::DagsterClient;
+use crate::error::DagsterError;
+use graphql_client::GraphQLQuery;
+
+#[derive(GraphQLQuery)]
+#[graphql(
+    schema_path = "queries/schema.json",
+    query_path = "queries/terminate_run.graphql",
+    response_derives = "Debug,Clone"
+)]
+pub struct TerminateRunMutation;
+
+/// Request termination of a run. Returns the ID of the run being terminated.
+pub async fn terminate_run(client: &DagsterClient, run_id: String) -> Result<String, DagsterError> {
+    let query_variables = terminate_run_mutation::Variables { run_id };
+    let data = client
+        .execute::<TerminateRunMutation>(query_variables)
+        .await?;
+
+    use terminate_run_mutation::TerminateRunMutationTerminateRun as TerminateRunResult;
+    match data.terminate_run {
+        TerminateRunResult::TerminateRunSuccess(success) => Ok(success.run.run_id),
+        TerminateRunResult::TerminateRunFailure(err) => Err(DagsterError::Dagster(err.message)),
+        TerminateRunResult::RunNotFoundError(err) => Err(DagsterError::RunNotFound(err.message)),
+        TerminateRunResult::UnauthorizedError(err) => Err(DagsterError::Dagster(err.message)),
+        TerminateRunResult::PythonError(err) => Err(DagsterError::Python {
+            message: err.message,
+            stack: err.stack,
+        }),
+    }
+}
@@ -0,0 +1,254 @@
+use ratatui::style::{Color, Modifier, Style};
+use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fs;
+use std::path::PathBuf;
+
+/// The `[theme]` table in config.toml
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ThemeConfig {
+    pub preset: ThemePreset,
+    /// TOML file of colors applied over the preset, e.g. one shared by a team
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<PathBuf>,
+    /// Colors applied over the preset and `file`, keyed by `Theme` field
+    #[serde(skip_serializing_if = "toml::Table::is_empty")]
+    pub colors: toml::Table,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThemePreset {
+    #[default]
+    Dark,
+    Light,
+    Solarized,
+    HighContrast,
+}
+
+impl ThemeConfig {
+    pub fn is_default(&self) -> bool {
+        *self == ThemeConfig::default()
+    }
+
+    /// Build the theme: the preset, then `file`, then `colors`.
+    /// A non-empty `NO_COLOR` environment variable turns colors off entirely
+    pub fn resolve(&self) -> Result<Theme, Box<dyn Error>> {
+        let theme = self.resolve_colors()?;
+        if std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty()) {
+            return Ok(Theme::no_color());
+        }
+        Ok(theme)
+    }
+
+    fn resolve_colors(&self) -> Result<Theme, Box<dyn Error>> {
+        let mut colors = toml::Table::try_from(Theme::preset(self.preset))?;
+        if let Some(path) = &self.file {
+            let contents = fs::read_to_string(path)
+                .map_err(|e| format!("Can't read theme file {}: {}", path.display(), e))?;
+            let file_colors: toml::Table = toml::from_str(&contents)
+                .map_err(|e| format!("Invalid theme file {}: {}", path.display(), e))?;
+            colors.extend(file_colors);
+        }
+        colors.extend(self.colors.clone());
+        Ok(colors
+            .try_into()
+            .map_err(|e| format!("Invalid theme colors: {}", e))?)
+    }
+}
+
+/// Colors used across the UI, named for what they mean rather than how they look
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Theme {
+    /// Successful runs, a working connection and the current context
+    pub success: Color,
+    /// Failed runs and errors
+    pub failure: Color,
+    /// Starting and in-progress runs
+    pub running: Color,
+    pub queued: Color,
+    pub canceled: Color,
+    /// Context name and URL in the context bar
+    pub accent: Color,
+    /// Section headings
+    pub heading: Color,
+    /// Command names and marked runs
+    pub highlight: Color,
+    /// Background of the selected row and the command/search input
+    pub selection: Color,
+    /// Read-only mode, status messages and the paused badge
+    pub warning: Color,
+    /// Less important text, e.g. debug log records
+    pub muted: Color,
+    /// Text on `failure`, as in the production banner
+    pub on_failure: Color,
+    /// Text on `warning`, as in the paused badge
+    pub on_warning: Color,
+    /// Draw with reverse video instead of colors, for `NO_COLOR`
+    #[serde(skip)]
+    pub no_color: bool,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::preset(ThemePreset::Dark)
+    }
+}
+
+impl Theme {
+    pub fn preset(preset: ThemePreset) -> Self {
+        match preset {
+            ThemePreset::Dark => Self {
+                success: Color::Green,
+                failure: Color::Red,
+                running: Color::Blue,
+                queued: Color::Yellow,
+                canceled: Color::DarkGray,
+                accent: Color::Blue,
+                heading: Color::LightBlue,
+                highlight: Color::Cyan,
+                selection: Color::DarkGray,
+                warning: Color::Yellow,
+                muted: Color::Gray,
+                on_failure: Color::White,
+                on_warning: Color::Black,
+                no_color: false,
+            },
+            // Yellow and cyan wash out on a white background
+            ThemePreset::Light => Self {
+                success: Color::Indexed(28),
+                failure: Color::Indexed(160),
+                running: Color::Indexed(25),
+                queued: Color::Indexed(130),
+                canceled: Color::Indexed(244),
+                accent: Color::Indexed(25),
+                heading: Color::Indexed(24),
+                highlight: Color::Indexed(90),
+                selection: Color::Indexed(253),
+                warning: Color::Indexed(130),
+                muted: Color::Indexed(244),
+                on_failure: Color::White,
+                on_warning: Color::White,
+                no_color: false,
+            },
+            ThemePreset::Solarized => Self {
+                success: Color::Rgb(0x85, 0x99, 0x00),
+                failure: Color::Rgb(0xdc, 0x32, 0x2f),
+                running: Color::Rgb(0x26, 0x8b, 0xd2),
+                queued: Color::Rgb(0xb5, 0x89, 0x00),
+                canceled: Color::Rgb(0x58, 0x6e, 0x75),
+                accent: Color::Rgb(0x26, 0x8b, 0xd2),
+                heading: Color::Rgb(0x6c, 0x71, 0xc4),
+                highlight: Color::Rgb(0x2a, 0xa1, 0x98),
+                selection: Color::Rgb(0x07, 0x36, 0x42),
+                warning: Color::Rgb(0xcb, 0x4b, 0x16),
+                muted: Color::Rgb(0x58, 0x6e, 0x75),
+                on_failure: Color::Rgb(0xfd, 0xf6, 0xe3),
+                on_warning: Color::Rgb(0xfd, 0xf6, 0xe3),
+                no_color: false,
+            },
+            ThemePreset::HighContrast => Self {
+                success: Color::LightGreen,
+                failure: Color::LightRed,
+                running: Color::LightCyan,
+                queued: Color::LightYellow,
+                canceled: Color::White,
+                accent: Color::LightCyan,
+                heading: Color::White,
+                highlight: Color::LightMagenta,
+                selection: Color::Blue,
+                warning: Color::LightYellow,
+                muted: Color::White,
+                on_failure: Color::Black,
+                on_warning: Color::Black,
+                no_color: false,
+            },
+        }
+    }
+
+    /// Terminal default colors everywhere, relying on reverse video and bold instead
+    pub fn no_color() -> Self {
+        Self {
+            success: Color::Reset,
+            failure: Color::Reset,
+            running: Color::Reset,
+            queued: Color::Reset,
+            canceled: Color::Reset,
+            accent: Color::Reset,
+            heading: Color::Reset,
+            highlight: Color::Reset,
+            selection: Color::Reset,
+            warning: Color::Reset,
+            muted: Color::Reset,
+            on_failure: Color::Reset,
+            on_warning: Color::Reset,
+            no_color: true,
+        }
+    }
+
+    /// Text style for a Dagster run status
+    pub fn status_style(&self, status: &str) -> Style {
+        let color = match status {
+            "SUCCESS" => self.success,
+            "FAILURE" => self.failure,
+            "STARTED" | "STARTING" => self.running,
+            "QUEUED" => self.queued,
+            "CANCELED" => self.canceled,
+            _ => return Style::default(),
+        };
+        Style::default().fg(color)
+    }
+
+    /// Highlights the selected row of a list
+    pub fn selected(&self, style: Style) -> Style {
+        if self.no_color {
+            style.add_modifier(Modifier::REVERSED | Modifier::BOLD)
+        } else {
+            style.bg(self.selection).add_modifier(Modifier::BOLD)
+        }
+    }
+
+    /// The command and search input line
+    pub fn input(&self) -> Style {
+        if self.no_color {
+            Style::default().add_modifier(Modifier::REVERSED)
+        } else {
+            Style::default().bg(self.selection)
+        }
+    }
+
+    /// A badge such as PRODUCTION, drawn as `text` on `background`
+    pub fn badge(&self, text: Color, background: Color) -> Style {
+        let style = Style::default().add_modifier(Modifier::BOLD);
+        if self.no_color {
+            style.add_modifier(Modifier::REVERSED)
+        } else {
+            style.fg(text).bg(background)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_theme_overrides() {
+        let config: ThemeConfig = toml::from_str(
+            r##"
+            preset = "light"
+            [colors]
+            failure = "#ff0000"
+            "##,
+        )
+        .unwrap();
+        let theme = config.resolve_colors().unwrap();
+        assert_eq!(theme.failure, Color::Rgb(0xff, 0, 0));
+        assert_eq!(theme.success, Theme::preset(ThemePreset::Light).success);
+
+        let config: ThemeConfig = toml::from_str("[colors]\nfailur = \"red\"").unwrap();
+        assert!(config.resolve_colors().is_err());
+    }
+}
@@ -1,15 +1,20 @@
-use crate::app::{App, ConnectionStatus};
-use crate::views::ContextsView;
+use crate::aliases;
+use crate::app::{App, COMMANDS, Confirmation, ConnectionStatus, HelpPage};
+use crate::config::ContextMode;
+use crate::keys::{Action, Key, KeyScope};
+use crate::theme::Theme;
+use crate::utils::truncate;
+use crate::views::{ContextsView, DebugLogView, key_hints};
 use crate::views::{DefaultView, PipelinesView, RunsView, ViewType, ViewUI};
 use ratatui::{
     prelude::*,
-    style::{Color, Style},
+    style::{Modifier, Style},
     text::Line,
     widgets::*,
 };
 
 pub fn draw(f: &mut Frame, app: &App) {
-    log::debug!(
+    log::trace!(
         "Drawing UI frame. Current view: {:?}, Items count: {}",
         app.view,
         app.items.len()
@@ -25,42 +30,99 @@ pub fn draw(f: &mut Frame, app: &App) {
         .split(f.area());
 
     // Context bar with URL and status
+    let theme = &app.theme;
     let status_style = match &app.connection_status {
-        ConnectionStatus::Connected => Style::default().fg(Color::Green),
-        ConnectionStatus::Failed(_) => Style::default().fg(Color::Red),
-        ConnectionStatus::Disconnected => Style::default().fg(Color::Yellow),
+        ConnectionStatus::Connected => Style::default().fg(theme.success),
+        ConnectionStatus::Failed(_) => Style::default().fg(theme.failure),
+        ConnectionStatus::Disconnected => Style::default().fg(theme.warning),
     };
 
     let status_text = match &app.connection_status {
-        ConnectionStatus::Connected => "Connected",
-        ConnectionStatus::Failed(err) => {
-            if err.len() > 30 {
-                &err[..30]
-            } else {
-                err
-            }
-        }
-        ConnectionStatus::Disconnected => "Disconnected",
+        ConnectionStatus::Connected => "Connected".to_string(),
+        ConnectionStatus::Failed(err) => format!("{} (e for details)", truncate(err, 30)),
+        ConnectionStatus::Disconnected => "Disconnected".to_string(),
     };
 
-    let context_line = Line::from(vec![
-        Span::raw("URL: "),
-        Span::styled(&app.dagster_url, Style::default().fg(Color::Blue)),
+    let context = app.config.get_current_context();
+    let mut context_spans = Vec::new();
+    if context.production {
+        // Hard to miss, so nobody mistakes prod for a dev instance
+        context_spans.push(Span::styled(
+            " PRODUCTION ",
+            theme.badge(theme.on_failure, theme.failure),
+        ));
+        context_spans.push(Span::raw(" "));
+    }
+    if app.paused {
+        context_spans.push(Span::styled(
+            " PAUSED ",
+            theme.badge(theme.on_warning, theme.warning),
+        ));
+        context_spans.push(Span::raw(" "));
+    }
+    context_spans.extend([
+        Span::raw("Context: "),
+        Span::styled(&app.config.last_context, Style::default().fg(theme.accent)),
+    ]);
+    if context.insecure_tls() {
+        context_spans.push(Span::styled(
+            " [INSECURE TLS]",
+            Style::default()
+                .fg(theme.failure)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    if app.context_mode() == ContextMode::ReadOnly {
+        context_spans.push(Span::styled(
+            " [READ-ONLY]",
+            Style::default().fg(theme.warning),
+        ));
+    }
+    context_spans.extend([
+        Span::raw(" | URL: "),
+        Span::styled(app.client.url(), Style::default().fg(theme.accent)),
         Span::raw(" | Status: "),
         Span::styled(status_text, status_style),
     ]);
+    if let Some(last_updated) = app.last_updated
+        && app.view.poll_key().is_some()
+    {
+        context_spans.push(Span::raw(format!(
+            " | Updated {}s ago",
+            last_updated.elapsed().as_secs()
+        )));
+    }
+    if let Some(message) = &app.status_message {
+        context_spans.push(Span::raw(" | "));
+        context_spans.push(Span::styled(message, Style::default().fg(theme.warning)));
+    }
+    let context_line = Line::from(context_spans);
 
     let context = Paragraph::new(context_line);
     f.render_widget(context, chunks[0]);
 
     // Command or search input
     if app.command_mode {
-        let input = Paragraph::new(format!(": {}", app.command_input))
-            .style(Style::default().bg(Color::DarkGray));
+        let command_line = &app.command_line;
+        let mut spans = vec![Span::raw(": "), Span::raw(command_line.input())];
+        if let Some(error) = &command_line.error {
+            spans.push(Span::styled(
+                format!("  {}", error),
+                Style::default().fg(theme.failure),
+            ));
+        } else if !command_line.completions().is_empty() {
+            spans.push(Span::styled(
+                format!("  {}", command_line.completions().join("  ")),
+                Style::default().fg(theme.muted),
+            ));
+        }
+        let input = Paragraph::new(Line::from(spans)).style(theme.input());
         f.render_widget(input, chunks[1]);
+
+        let before_cursor = Span::raw(&command_line.input()[..command_line.cursor()]);
+        f.set_cursor_position((chunks[1].x + 2 + before_cursor.width() as u16, chunks[1].y));
     } else if app.search_mode {
-        let input = Paragraph::new(format!("/{}", app.search_input))
-            .style(Style::default().bg(Color::DarkGray));
+        let input = Paragraph::new(format!("/{}", app.search_input)).style(theme.input());
         f.render_widget(input, chunks[1]);
     }
 
@@ -88,9 +150,291 @@ pub fn draw(f: &mut Frame, app: &App) {
             let contexts_view = ContextsView::new();
             contexts_view.draw(f, app, chunks[2]);
         }
+        ViewType::DebugLog => {
+            let debug_log_view = DebugLogView::new();
+            debug_log_view.draw(f, app, chunks[2]);
+        }
         ViewType::Default => {
             let default_view = DefaultView;
             default_view.draw(f, app, chunks[2]);
         }
     }
+
+    // Confirmation prompts are drawn over the current view
+    if let Some(confirmation) = &app.confirmation {
+        draw_confirmation(f, confirmation, theme, chunks[2]);
+    } else if app.show_error {
+        draw_error_panel(f, app, chunks[2]);
+    } else if let Some(page) = app.help {
+        draw_help_panel(f, app, page, chunks[2]);
+    }
+}
+
+/// Draws the keys and commands that work in the current view and input mode, the aliases
+/// or the saved views
+fn draw_help_panel(f: &mut Frame, app: &App, page: HelpPage, area: Rect) {
+    let theme = &app.theme;
+    let heading = |text: &str| {
+        Line::styled(
+            text.to_string(),
+            Style::default()
+                .fg(theme.heading)
+                .add_modifier(Modifier::BOLD),
+        )
+    };
+    let entry = |keys: String, description: &str| {
+        Line::from(vec![
+            Span::styled(
+                format!("  {:<16} ", keys),
+                Style::default()
+                    .fg(theme.highlight)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(description.to_string()),
+        ])
+    };
+    let bindings = |scope: KeyScope| -> Vec<Line> {
+        app.keymap
+            .bindings(scope)
+            .iter()
+            .filter(|(action, keys)| {
+                !keys.is_empty() && (*action != Action::Search || app.view.is_searchable())
+            })
+            .map(|(action, keys)| {
+                let keys: Vec<String> = keys.iter().map(Key::to_string).collect();
+                entry(keys.join("/"), action.description())
+            })
+            .collect()
+    };
+
+    let aliases = || -> Vec<Line> {
+        aliases::all(&app.config.aliases)
+            .into_iter()
+            .map(|(alias, command)| entry(format!(":{}", alias), &format!(":{}", command)))
+            .collect()
+    };
+
+    let mut lines = Vec::new();
+    let title = if page == HelpPage::Aliases {
+        lines.push(heading("Aliases"));
+        lines.extend(aliases());
+        "Aliases"
+    } else if page == HelpPage::Views {
+        lines.push(heading("Saved Views"));
+        if app.config.views.is_empty() {
+            lines.push(Line::from("  None yet: open a view and :save <name> it"));
+        }
+        let mut views: Vec<_> = app.config.views.iter().collect();
+        views.sort_by_key(|(name, _)| *name);
+        lines.extend(
+            views
+                .into_iter()
+                .map(|(name, view)| entry(format!(":view {}", name), &view.summary())),
+        );
+        "Saved Views"
+    } else if app.command_mode || app.search_mode {
+        let (title, keys): (&str, &[(&str, &str)]) = if app.command_mode {
+            (
+                "Command Mode",
+                &[
+                    ("Enter", "run the command"),
+                    ("Esc", "cancel"),
+                    ("Tab", "complete a command, context, job, run ID or view"),
+                    ("↑/↓", "previous and next commands"),
+                    ("←/→", "move the cursor"),
+                    ("Alt-b/Alt-f", "move by word"),
+                    ("Ctrl-a/Ctrl-e", "go to the start or end"),
+                    ("Backspace", "delete a character"),
+                    ("Ctrl-w", "delete the previous word"),
+                    ("Ctrl-u", "delete to the start"),
+                ],
+            )
+        } else {
+            (
+                "Search",
+                &[
+                    ("Enter", "keep the filter and return to the list"),
+                    ("Esc", "cancel"),
+                    ("Backspace", "delete a character"),
+                ],
+            )
+        };
+        lines.push(heading(title));
+        lines.extend(
+            keys.iter()
+                .map(|(keys, description)| entry(keys.to_string(), description)),
+        );
+        title
+    } else {
+        if let Some(scope) = KeyScope::of(&app.view) {
+            lines.push(heading(app.view.title()));
+            lines.extend(bindings(scope));
+            lines.push(Line::from(""));
+        }
+        lines.push(heading("Global"));
+        lines.extend(bindings(KeyScope::Global));
+        app.view.title()
+    };
+
+    if page == HelpPage::Keys && !app.search_mode {
+        lines.push(Line::from(""));
+        lines.push(heading("Commands"));
+        lines.extend(
+            COMMANDS
+                .iter()
+                .map(|(usage, description)| entry(format!(":{}", usage), description)),
+        );
+        lines.push(Line::from(""));
+        lines.push(heading("Aliases"));
+        lines.extend(aliases());
+    }
+
+    let popup_area = Rect::new(
+        area.x + area.width / 10,
+        area.y + area.height / 10,
+        area.width - area.width / 5,
+        area.height - area.height / 5,
+    );
+
+    let popup = Paragraph::new(lines)
+        .scroll((app.help_scroll as u16, 0))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(theme.accent))
+                .title(format!(" Help: {} ", title))
+                .title_alignment(Alignment::Center)
+                .title_bottom(panel_hints(app).centered()),
+        );
+
+    f.render_widget(Clear, popup_area);
+    f.render_widget(popup, popup_area);
+}
+
+/// Footer for the help and error panels, showing the keys currently bound
+fn panel_hints(app: &App) -> Line<'static> {
+    let mut hints = key_hints(
+        app,
+        &[
+            (&[Action::Up, Action::Down], "Scroll"),
+            (&[Action::Back], "Close"),
+        ],
+    );
+    hints.spans.insert(0, Span::raw(" "));
+    hints.spans.push(Span::raw(" "));
+    hints
+}
+
+/// Draws the full text of the last request error, scrollable with the up and down keys
+fn draw_error_panel(f: &mut Frame, app: &App, area: Rect) {
+    let (title, lines) = match &app.last_error {
+        Some(error) => (
+            format!(" {} ", error.kind()),
+            error.details().into_iter().map(Line::from).collect(),
+        ),
+        None => (" Errors ".to_string(), vec![Line::from("No errors so far")]),
+    };
+
+    let popup_area = Rect::new(
+        area.x + area.width / 10,
+        area.y + area.height / 10,
+        area.width - area.width / 5,
+        area.height - area.height / 5,
+    );
+
+    let popup = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .scroll((app.error_scroll as u16, 0))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(app.theme.failure))
+                .title(title)
+                .title_alignment(Alignment::Center)
+                .title_bottom(panel_hints(app).centered()),
+        );
+
+    f.render_widget(Clear, popup_area);
+    f.render_widget(popup, popup_area);
+}
+
+/// Draws a centered popup summarizing a pending bulk action
+fn draw_confirmation(f: &mut Frame, confirmation: &Confirmation, theme: &Theme, area: Rect) {
+    // Show at most this many runs, then summarize the rest
+    const MAX_LISTED_RUNS: usize = 10;
+
+    let mut lines = vec![
+        Line::styled(
+            format!(
+                "{} {} run(s)?",
+                confirmation.action.verb(),
+                confirmation.run_ids.len()
+            ),
+            Style::default().add_modifier(Modifier::BOLD),
+        ),
+        Line::from(""),
+    ];
+    lines.extend(
+        confirmation
+            .summary
+            .iter()
+            .take(MAX_LISTED_RUNS)
+            .map(|line| Line::from(format!("  {}", line))),
+    );
+    if confirmation.summary.len() > MAX_LISTED_RUNS {
+        lines.push(Line::from(format!(
+            "  ...and {} more",
+            confirmation.summary.len() - MAX_LISTED_RUNS
+        )));
+    }
+    lines.push(Line::from(""));
+    if confirmation.typed {
+        let expected = match confirmation.run_ids.as_slice() {
+            [run_id] => format!("{} or yes", run_id),
+            _ => "yes".to_string(),
+        };
+        lines.push(Line::styled(
+            "This cannot be undone.",
+            Style::default()
+                .fg(theme.failure)
+                .add_modifier(Modifier::BOLD),
+        ));
+        lines.push(Line::from(vec![
+            Span::raw("Type "),
+            Span::styled(expected, Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" and press Enter to confirm, Esc to cancel"),
+        ]));
+        lines.push(Line::from(format!("> {}", confirmation.input)));
+    } else {
+        lines.push(Line::from(vec![
+            Span::raw("Press "),
+            Span::styled("y", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(" to confirm, any other key to cancel"),
+        ]));
+    }
+
+    let width = lines
+        .iter()
+        .map(|line| line.width() as u16 + 4)
+        .max()
+        .unwrap_or(0)
+        .min(area.width);
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let popup_area = Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    );
+
+    let popup = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(theme.failure))
+            .title(" Confirm ")
+            .title_alignment(Alignment::Center),
+    );
+
+    f.render_widget(Clear, popup_area);
+    f.render_widget(popup, popup_area);
 }
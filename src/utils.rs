@@ -1,18 +1,56 @@
 use chrono::{TimeZone, Utc};
+use serde::Serialize;
+use std::io::Write;
 
-pub fn get_status_style(status: &str) -> ratatui::style::Style {
-    use ratatui::style::Color;
+/// Every run status in the Dagster schema d9s was built against
+const KNOWN_RUN_STATUSES: [&str; 9] = [
+    "QUEUED",
+    "NOT_STARTED",
+    "MANAGED",
+    "STARTING",
+    "STARTED",
+    "SUCCESS",
+    "FAILURE",
+    "CANCELING",
+    "CANCELED",
+];
 
-    match status.trim_matches('"') {
-        "SUCCESS" => ratatui::style::Style::default().fg(Color::Green),
-        "FAILURE" => ratatui::style::Style::default().fg(Color::Red),
-        "STARTED" | "STARTING" => ratatui::style::Style::default().fg(Color::Blue),
-        "QUEUED" => ratatui::style::Style::default().fg(Color::Yellow),
-        "CANCELED" => ratatui::style::Style::default().fg(Color::DarkGray),
-        _ => ratatui::style::Style::default(),
+/// The name Dagster uses for a run status, e.g. `FAILURE`. Works for the `RunStatus` enum
+/// generated for any query, and gives the raw value for statuses the schema doesn't know.
+pub fn run_status_name(status: &impl Serialize) -> String {
+    match serde_json::to_value(status) {
+        Ok(serde_json::Value::String(name)) => name,
+        _ => "UNKNOWN".to_string(),
     }
 }
 
+/// Whether `status` is one d9s knows how to interpret
+pub fn is_known_run_status(status: &str) -> bool {
+    KNOWN_RUN_STATUSES.contains(&status)
+}
+
+/// Whether a run is starting, in progress or being canceled
+pub fn is_run_active(status: &str) -> bool {
+    matches!(status, "STARTING" | "STARTED" | "CANCELING")
+}
+
+/// The Dagster statuses meant by a word such as `failed` or `running`, for `:runs` filters
+pub fn run_statuses(word: &str) -> Option<&'static [&'static str]> {
+    Some(match word.to_lowercase().as_str() {
+        "failed" | "failure" => &["FAILURE"],
+        "success" | "succeeded" => &["SUCCESS"],
+        "running" | "started" => &["STARTING", "STARTED"],
+        "queued" => &["QUEUED", "NOT_STARTED"],
+        "canceled" | "cancelled" => &["CANCELING", "CANCELED"],
+        _ => return None,
+    })
+}
+
+/// Whether a run has reached a terminal status and won't change any more
+pub fn is_run_finished(status: &str) -> bool {
+    matches!(status, "SUCCESS" | "FAILURE" | "CANCELED")
+}
+
 pub fn format_timestamp(timestamp: Option<f64>) -> String {
     timestamp.map_or("-".to_string(), |ts| {
         let utc_time = Utc.timestamp_opt(ts as i64, 0).single().unwrap_or_default();
@@ -80,9 +118,42 @@ pub fn format_duration(start: Option<f64>, end: Option<f64>) -> String {
 }
 
 pub fn truncate(s: &str, max_width: usize) -> String {
-    if s.len() <= max_width {
+    if s.chars().count() <= max_width {
         s.to_string()
+    } else if max_width <= 3 {
+        s.chars().take(max_width).collect()
     } else {
-        format!("{}...", &s[0..max_width - 3])
+        let head: String = s.chars().take(max_width - 3).collect();
+        format!("{}...", head)
+    }
+}
+
+/// Copies text to the system clipboard with the OSC 52 terminal escape sequence.
+/// This works over SSH and in most modern terminals without a platform clipboard library.
+pub fn copy_to_clipboard(text: &str) -> std::io::Result<()> {
+    use base64::Engine;
+
+    let encoded = base64::engine::general_purpose::STANDARD.encode(text);
+    let mut stdout = std::io::stdout();
+    write!(stdout, "\x1b]52;c;{}\x07", encoded)?;
+    stdout.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::get_runs::runs_query::RunStatus;
+
+    #[test]
+    fn test_run_status_name() {
+        assert_eq!(run_status_name(&RunStatus::FAILURE), "FAILURE");
+        assert_eq!(run_status_name(&RunStatus::NOT_STARTED), "NOT_STARTED");
+        assert!(is_run_finished(&run_status_name(&RunStatus::CANCELED)));
+
+        // Statuses added to Dagster after this schema keep their name, but aren't known
+        let paused = run_status_name(&RunStatus::Other("PAUSED".to_string()));
+        assert_eq!(paused, "PAUSED");
+        assert!(!is_known_run_status(&paused));
+        assert!(is_known_run_status("QUEUED"));
     }
 }
@@ -1,17 +1,18 @@
 use crate::app::App;
+use crate::keys::Action;
 use crate::views::ViewUI;
 use async_trait::async_trait;
 use ratatui::{
     prelude::*,
-    style::{Color, Modifier, Style},
-    text::{Line, Span},
+    style::{Modifier, Style},
+    text::Line,
     widgets::*,
 };
 use std::error::Error;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-use super::ViewPoller;
+use super::{ViewPoller, key_hints};
 
 pub struct ContextsView;
 
@@ -42,7 +43,7 @@ impl ViewUI for ContextsView {
             .items
             .iter()
             .skip(app.list_offset)
-            .take(viewport_height as usize)
+            .take(viewport_height)
             .enumerate()
             .map(|(i, item)| {
                 let actual_index = i + app.list_offset;
@@ -51,7 +52,7 @@ impl ViewUI for ContextsView {
                 if actual_index == 0 {
                     // Header
                     Line::styled(
-                        "CONTEXT NAME     URL                                      RUNS LIMIT",
+                        "CONTEXT NAME     URL                                      RUNS LIMIT  MODE",
                         Style::default().add_modifier(Modifier::BOLD),
                     )
                 } else if actual_index == 1 {
@@ -62,12 +63,12 @@ impl ViewUI for ContextsView {
 
                     // Current context in green
                     if item.starts_with('*') {
-                        style = style.fg(Color::Green);
+                        style = style.fg(app.theme.success);
                     }
 
                     // Selection highlighting
                     if is_selected {
-                        style = style.bg(Color::DarkGray).add_modifier(Modifier::BOLD);
+                        style = app.theme.selected(style);
                     }
 
                     Line::styled(item.clone(), style)
@@ -87,16 +88,16 @@ impl ViewUI for ContextsView {
         // Footer with keybindings
         let footer_area = Rect::new(area.x, area.y + area.height - 1, area.width, 1);
 
-        let footer = Line::from(vec![
-            Span::styled("a", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(" Add | "),
-            Span::styled("d", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(" Delete | "),
-            Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(" Select | "),
-            Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(" Back"),
-        ]);
+        let footer = key_hints(
+            app,
+            &[
+                (&[Action::Add], "Add"),
+                (&[Action::Delete], "Delete"),
+                (&[Action::Open], "Select"),
+                (&[Action::Back], "Back"),
+                (&[Action::Help], "Help"),
+            ],
+        );
 
         let footer_widget = Paragraph::new(footer).alignment(Alignment::Center);
         f.render_widget(footer_widget, footer_area);
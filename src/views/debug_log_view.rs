@@ -0,0 +1,146 @@
+use crate::app::App;
+use crate::keys::Action;
+use crate::logging::{LogRecord, recent_records};
+use crate::theme::Theme;
+use crate::views::ViewUI;
+use async_trait::async_trait;
+use log::Level;
+use ratatui::{
+    prelude::*,
+    style::{Modifier, Style},
+    text::Line,
+    widgets::*,
+};
+use std::error::Error;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use super::{ViewPoller, key_hints};
+
+/// d9s's own recent log records: request timings, failures and polling
+pub struct DebugLogView;
+
+impl DebugLogView {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    /// Rebuild the list from the in-memory log, keeping any search filter
+    pub fn populate_items(app: &mut App) {
+        let mut items = vec!["HEADER".to_string(), "SEPARATOR".to_string()];
+        items.extend(recent_records().iter().map(record_row));
+
+        if app.search_mode || app.has_committed_filter {
+            app.unfiltered_items = items;
+            app.apply_search_filter();
+        } else {
+            app.items = items;
+        }
+    }
+}
+
+/// Formats a record as a list row. The level is always the second word
+fn record_row(record: &LogRecord) -> String {
+    format!(
+        "{} {:<5} {:<22} {}",
+        record.time.format("%H:%M:%S%.3f"),
+        record.level,
+        record.target,
+        record.message.replace('\n', " ")
+    )
+}
+
+fn level_style(row: &str, theme: &Theme) -> Style {
+    match row.split_whitespace().nth(1).and_then(|l| l.parse().ok()) {
+        Some(Level::Error) => Style::default().fg(theme.failure),
+        Some(Level::Warn) => Style::default().fg(theme.warning),
+        Some(Level::Debug | Level::Trace) => Style::default().fg(theme.muted),
+        _ => Style::default(),
+    }
+}
+
+#[async_trait]
+impl ViewPoller for DebugLogView {
+    async fn poll(
+        &self,
+        app: Arc<Mutex<App>>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let mut app_lock = app.lock().await;
+        if matches!(app_lock.view, super::ViewType::DebugLog) {
+            Self::populate_items(&mut app_lock);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ViewUI for DebugLogView {
+    fn draw(&self, f: &mut Frame, app: &App, area: Rect) {
+        let viewport_height = area.height as usize;
+
+        let visible_items: Vec<Line> = app
+            .items
+            .iter()
+            .skip(app.list_offset)
+            .take(viewport_height)
+            .enumerate()
+            .map(|(i, item)| {
+                let actual_index = i + app.list_offset;
+
+                if actual_index == 0 {
+                    Line::styled(
+                        format!(
+                            "{:<12} {:<5} {:<22} {}",
+                            "TIME", "LEVEL", "SOURCE", "MESSAGE"
+                        ),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    )
+                } else if actual_index == 1 {
+                    Line::from("-".repeat((area.width - 2) as usize))
+                } else {
+                    let mut style = level_style(item, &app.theme);
+                    if actual_index == app.selected_index {
+                        style = app.theme.selected(style);
+                    }
+                    Line::styled(item.clone(), style)
+                }
+            })
+            .collect();
+
+        let paragraph = Paragraph::new(visible_items).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Debug Log ")
+                .title_alignment(Alignment::Center),
+        );
+
+        f.render_widget(paragraph, area);
+
+        // Footer with keybindings
+        let footer_area = Rect::new(area.x, area.y + area.height - 1, area.width, 1);
+
+        let footer = key_hints(
+            app,
+            &[
+                (&[Action::Up, Action::Down], "Navigate"),
+                (&[Action::Search], "Search"),
+                (&[Action::Pause], "Pause"),
+                (&[Action::Back], "Back"),
+                (&[Action::Help], "Help"),
+            ],
+        );
+
+        let footer_widget = Paragraph::new(footer).alignment(Alignment::Center);
+        f.render_widget(footer_widget, footer_area);
+    }
+
+    async fn restore_state(&self, app: &mut App) -> Result<(), Box<dyn Error + Send + Sync>> {
+        Self::populate_items(app);
+
+        // Newest records are at the top
+        app.selected_index = 2.min(app.items.len().saturating_sub(1));
+        app.list_offset = 0;
+
+        Ok(())
+    }
+}
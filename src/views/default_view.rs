@@ -1,9 +1,10 @@
-use crate::app::App;
+use crate::app::{App, COMMANDS};
+use crate::keys::KeyScope;
 use crate::views::ViewUI;
 use async_trait::async_trait;
 use ratatui::{
     prelude::*,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::*,
 };
@@ -21,25 +22,25 @@ impl DefaultView {
         app.items.push("Welcome to d9s!".to_string());
         app.items.push("".to_string());
         app.items.push("Available commands:".to_string());
-        app.items
-            .push("  :runs - Show all pipeline runs".to_string());
-        app.items
-            .push("  :pipelines - Show available pipelines".to_string());
-        app.items
-            .push("  :contexts - Manage connection contexts".to_string());
-        app.items
-            .push("  :url <url> - Set Dagster GraphQL URL".to_string());
-        app.items
-            .push("  :context <name> - Switch to a different context".to_string());
-        app.items
-            .push("  :debug - Log application debug information".to_string());
-        app.items.push("  :q - Quit application".to_string());
+        app.items.extend(
+            COMMANDS
+                .iter()
+                .map(|(usage, description)| format!("  :{} - {}", usage, description)),
+        );
         app.items.push("".to_string());
         app.items.push("Navigation:".to_string());
-        app.items.push("  Press 'q' to quit".to_string());
-        app.items
-            .push("  Press ':' to enter command mode".to_string());
-        app.items.push("  Press 'Esc' to go back".to_string());
+        // Generated from the active bindings so remapped keys show up here
+        let navigation: Vec<String> = app
+            .keymap
+            .bindings(KeyScope::Global)
+            .iter()
+            .filter(|(_, keys)| !keys.is_empty())
+            .map(|(action, keys)| {
+                let keys: Vec<String> = keys.iter().map(|key| format!("'{}'", key)).collect();
+                format!("  Press {} to {}", keys.join(" or "), action.description())
+            })
+            .collect();
+        app.items.extend(navigation);
     }
 }
 
@@ -56,7 +57,7 @@ impl ViewPoller for DefaultView {
 #[async_trait::async_trait]
 impl ViewUI for DefaultView {
     fn draw(&self, f: &mut Frame, app: &App, area: Rect) {
-        let light_blue = Color::LightBlue;
+        let theme = &app.theme;
 
         let lines: Vec<Line> = app
             .items
@@ -66,13 +67,15 @@ impl ViewUI for DefaultView {
                     Line::styled(
                         s.clone(),
                         Style::default()
-                            .fg(Color::Cyan)
+                            .fg(theme.highlight)
                             .add_modifier(Modifier::BOLD),
                     )
                 } else if s.starts_with("Available commands:") || s.starts_with("Navigation:") {
                     Line::styled(
                         s.clone(),
-                        Style::default().fg(light_blue).add_modifier(Modifier::BOLD),
+                        Style::default()
+                            .fg(theme.heading)
+                            .add_modifier(Modifier::BOLD),
                     )
                 } else if s.starts_with("  :") {
                     // Command with different colors for the command name and description
@@ -80,7 +83,7 @@ impl ViewUI for DefaultView {
                     let parts: Vec<&str> = s.splitn(2, " - ").collect();
                     if parts.len() > 1 {
                         Line::from(vec![
-                            Span::styled(parts[0], Style::default().fg(Color::Cyan)),
+                            Span::styled(parts[0], Style::default().fg(theme.highlight)),
                             Span::raw(" - "),
                             Span::raw(parts[1]),
                         ])
@@ -100,7 +103,7 @@ impl ViewUI for DefaultView {
                     .title(" d9s ")
                     .title_alignment(Alignment::Center),
             )
-            .alignment(Alignment::Center); 
+            .alignment(Alignment::Center);
 
         f.render_widget(items, area);
     }
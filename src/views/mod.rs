@@ -1,30 +1,71 @@
+use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
 use crate::app::App;
+use crate::keys::{Action, Key};
 
 mod contexts_view;
+mod debug_log_view;
 mod default_view;
 mod pipelines_view;
 mod run_view;
 mod runs_view;
 pub use contexts_view::ContextsView;
+pub use debug_log_view::DebugLogView;
 pub use default_view::DefaultView;
-pub use pipelines_view::PipelinesView;
+pub use pipelines_view::{PipelinesView, pipeline_row};
 use ratatui::{Frame, prelude::*};
 pub use run_view::{Run, RunPoller, RunView};
-pub use runs_view::RunsView;
+pub use runs_view::{RunsView, row_run_id, run_cell, run_row};
 
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ViewType {
+    #[default]
     Default,
     Runs,
     Run(String),
     Contexts,
     Pipelines,
-    PipelineRuns(String), 
+    PipelineRuns(String),
+    DebugLog,
+}
+
+impl ViewType {
+    /// Key for this view's polling interval in config.toml, or None if it doesn't poll
+    pub fn poll_key(&self) -> Option<&'static str> {
+        match self {
+            ViewType::Runs | ViewType::PipelineRuns(_) => Some("runs"),
+            ViewType::Run(_) => Some("run"),
+            ViewType::Pipelines => Some("pipelines"),
+            ViewType::DebugLog => Some("debuglog"),
+            ViewType::Default | ViewType::Contexts => None,
+        }
+    }
+
+    /// Whether `/` can filter this view
+    pub fn is_searchable(&self) -> bool {
+        matches!(
+            self,
+            ViewType::Runs | ViewType::PipelineRuns(_) | ViewType::Pipelines | ViewType::DebugLog
+        )
+    }
+
+    /// Name shown in the help overlay
+    pub fn title(&self) -> &'static str {
+        match self {
+            ViewType::Default => "Welcome",
+            ViewType::Runs => "Runs",
+            ViewType::Run(_) => "Run",
+            ViewType::Contexts => "Contexts",
+            ViewType::Pipelines => "Pipelines",
+            ViewType::PipelineRuns(_) => "Pipeline Runs",
+            ViewType::DebugLog => "Debug Log",
+        }
+    }
 }
 
 // Implement Hash for ViewType so it can be used as a key in HashMap
@@ -51,16 +92,13 @@ impl Hash for ViewType {
                 state.write_u8(5);
                 pipeline_name.hash(state);
             }
+            ViewType::DebugLog => {
+                state.write_u8(6);
+            }
         }
     }
 }
 
-impl Default for ViewType {
-    fn default() -> Self {
-        Self::Default
-    }
-}
-
 #[async_trait::async_trait]
 pub trait ViewPoller {
     async fn poll(
@@ -100,6 +138,51 @@ impl View for ContextsView {}
 #[async_trait::async_trait]
 impl View for PipelinesView {}
 
+#[async_trait::async_trait]
+impl View for DebugLogView {}
+
 // RunView is separate and implements both traits individually
 #[async_trait::async_trait]
 impl View for RunView {}
+
+/// A footer of key hints, showing the keys currently bound to each group of actions
+pub fn key_hints(app: &App, hints: &[(&[Action], &str)]) -> Line<'static> {
+    let mut spans = Vec::new();
+    for (actions, label) in hints {
+        let keys: Vec<Vec<Key>> = actions
+            .iter()
+            .map(|action| app.keymap.keys(&app.view, *action))
+            .filter(|keys| !keys.is_empty())
+            .collect();
+        if keys.is_empty() {
+            continue;
+        }
+
+        // `Space/V` for single keys, but `↑/k ↓/j` when actions have several
+        let separator = if keys.iter().all(|keys| keys.len() == 1) {
+            "/"
+        } else {
+            " "
+        };
+        let text = keys
+            .iter()
+            .map(|keys| {
+                keys.iter()
+                    .map(Key::to_string)
+                    .collect::<Vec<_>>()
+                    .join("/")
+            })
+            .collect::<Vec<_>>()
+            .join(separator);
+
+        if !spans.is_empty() {
+            spans.push(Span::raw(" | "));
+        }
+        spans.push(Span::styled(
+            text,
+            Style::default().add_modifier(Modifier::BOLD),
+        ));
+        spans.push(Span::raw(format!(" {}", label)));
+    }
+    Line::from(spans)
+}
@@ -1,18 +1,20 @@
-use crate::app::{App, ConnectionStatus};
+use crate::app::{App, ColumnsConfig, ConnectionStatus, PipelineColumn, SortColumn, SortState};
+use crate::error::DagsterError;
 use crate::get_pipelines::{Pipeline, get_pipelines};
-use crate::utils::{get_status_style, truncate};
+use crate::keys::Action;
+use crate::utils::truncate;
 use async_trait::async_trait;
 use ratatui::{
     prelude::*,
-    style::{Color, Modifier, Style},
-    text::{Line, Span},
+    style::{Modifier, Style},
+    text::Line,
     widgets::*,
 };
 use std::error::Error;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-use super::{ViewPoller, ViewUI};
+use super::{ViewPoller, ViewUI, key_hints};
 
 pub struct PipelinesView;
 
@@ -21,10 +23,7 @@ impl PipelinesView {
         Self {}
     }
 
-    pub async fn fetch_initial_data(
-        &self,
-        app: &mut App,
-    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    pub async fn fetch_initial_data(&self, app: &mut App) -> Result<(), DagsterError> {
         let pipelines = self.fetch_pipelines(app).await?;
 
         // Store pipelines in app state
@@ -36,14 +35,8 @@ impl PipelinesView {
         Ok(())
     }
 
-    pub async fn fetch_pipelines(
-        &self,
-        app: &App,
-    ) -> Result<Vec<Pipeline>, Box<dyn std::error::Error + Send + Sync>> {
-        match get_pipelines(app.dagster_url.clone()).await {
-            Ok(pipelines) => Ok(pipelines),
-            Err(e) => Err(e),
-        }
+    pub async fn fetch_pipelines(&self, app: &App) -> Result<Vec<Pipeline>, DagsterError> {
+        get_pipelines(&app.client).await
     }
 
     // Populate a target vector
@@ -56,29 +49,11 @@ impl PipelinesView {
         };
 
         target.clear();
-        target.push("PIPELINE NAME                                        REPOSITORY LOCATION                  LAST RUN STATUS".to_string());
-
-        // Add separator
-        target.push("-".repeat(80));
+        target.push("HEADER".to_string());
+        target.push("SEPARATOR".to_string());
 
         // Add the pipeline data
-        for pipeline in &pipelines {
-            // Skip asset jobs as per requirement
-            if pipeline.is_asset_job {
-                continue;
-            }
-
-            let status = pipeline
-                .last_run_status
-                .clone()
-                .unwrap_or_else(|| "None".to_string());
-
-            let name_col = format!("{:<50}", truncate(&pipeline.name, 50));
-            let repo_col = format!("{:<33}", truncate(&pipeline.repository_location, 33));
-            let status_col = status;
-
-            target.push(format!("{} {} {}", name_col, repo_col, status_col));
-        }
+        target.extend(pipeline_rows(pipelines, app.sort));
 
         log::debug!(
             "populate_pipelines_items_into: Created {} formatted items for target",
@@ -93,35 +68,9 @@ impl PipelinesView {
             state_lock.pipelines.clone()
         };
 
-        // Now create the formatted items directly
-        let mut items = Vec::new();
-
-        // Add header with properly aligned column names
-        items.push("PIPELINE NAME                                        REPOSITORY LOCATION                  LAST RUN STATUS".to_string());
-
-        // Add separator
-        items.push("-".repeat(80));
-
-        // Add the pipeline data
-        for pipeline in &pipelines {
-            // Skip asset jobs, I'm not sure if we actually want to do this.
-            // TODO: Look into how to get the identifier for an asset job, then display them in
-            // pipelines.
-            if pipeline.is_asset_job {
-                continue;
-            }
-
-            let status = pipeline
-                .last_run_status
-                .clone()
-                .unwrap_or_else(|| "None".to_string());
-
-            let name_col = format!("{:<50}", truncate(&pipeline.name, 50));
-            let repo_col = format!("{:<33}", truncate(&pipeline.repository_location, 33));
-            let status_col = status;
-
-            items.push(format!("{} {} {}", name_col, repo_col, status_col));
-        }
+        // Now create the formatted items directly, with header and separator placeholders
+        let mut items = vec!["HEADER".to_string(), "SEPARATOR".to_string()];
+        items.extend(pipeline_rows(pipelines, app.sort));
 
         app.items = items;
 
@@ -132,17 +81,59 @@ impl PipelinesView {
     }
 }
 
+/// Formats pipelines into list rows of space-separated cells, in the order of
+/// `ColumnsConfig::pipelines()`. Asset jobs are skipped.
+fn pipeline_rows(mut pipelines: Vec<Pipeline>, sort: Option<SortState>) -> Vec<String> {
+    // Skip asset jobs, I'm not sure if we actually want to do this.
+    // TODO: Look into how to get the identifier for an asset job, then display them in
+    // pipelines.
+    pipelines.retain(|pipeline| !pipeline.is_asset_job);
+
+    if let Some(SortState {
+        column: SortColumn::Pipeline(column),
+        descending,
+    }) = sort
+    {
+        pipelines.sort_by(|a, b| {
+            let ordering = match column {
+                PipelineColumn::Name => a.name.cmp(&b.name),
+                PipelineColumn::RepositoryLocation => {
+                    a.repository_location.cmp(&b.repository_location)
+                }
+                PipelineColumn::LastRunStatus => a.last_run_status.cmp(&b.last_run_status),
+            };
+            if descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+    }
+
+    pipelines.iter().map(pipeline_row).collect()
+}
+
+/// Formats a pipeline as a list row. This is also the text searches match against
+pub fn pipeline_row(pipeline: &Pipeline) -> String {
+    format!(
+        "{} {} {}",
+        pipeline.name,
+        pipeline.repository_location,
+        pipeline.last_run_status.as_deref().unwrap_or("None")
+    )
+}
+
 #[async_trait]
 impl ViewPoller for PipelinesView {
     async fn poll(
         &self,
         app: Arc<Mutex<App>>,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        let (should_poll, dagster_url) = {
+        let (should_poll, client) = {
             let app_lock = app.lock().await;
             (
                 matches!(app_lock.view, super::ViewType::Pipelines),
-                app_lock.dagster_url.clone(),
+                app_lock.client.clone(),
             )
         };
 
@@ -152,7 +143,7 @@ impl ViewPoller for PipelinesView {
 
         log::debug!("PipelinesPoller: Starting poll");
 
-        match get_pipelines(dagster_url).await {
+        match get_pipelines(&client).await {
             Ok(pipelines) => {
                 let mut app_lock = app.lock().await;
 
@@ -193,10 +184,10 @@ impl ViewPoller for PipelinesView {
 
                 // Only update error status if we're still in the Pipelines view
                 if matches!(app_lock.view, super::ViewType::Pipelines) {
-                    app_lock.connection_status = ConnectionStatus::Failed(e.to_string());
+                    app_lock.report_error(e.clone());
                 }
 
-                Err(e)
+                Err(Box::new(e))
             }
         }
     }
@@ -208,12 +199,15 @@ impl ViewUI for PipelinesView {
         let viewport_height = area.height as usize;
         let viewport_width = area.width;
 
+        let columns_config = ColumnsConfig::pipelines();
+        let dynamic_widths = columns_config.calculate_widths(viewport_width - 2); // Account for borders
+
         // Create spans for each visible item
         let visible_items: Vec<Line> = app
             .items
             .iter()
             .skip(app.list_offset)
-            .take(viewport_height as usize)
+            .take(viewport_height)
             .enumerate()
             .map(|(i, item)| {
                 let actual_index = i + app.list_offset;
@@ -221,7 +215,8 @@ impl ViewUI for PipelinesView {
 
                 if actual_index == 0 {
                     // Header
-                    Line::styled(item.clone(), Style::default().add_modifier(Modifier::BOLD))
+                    let header = columns_config.header(&dynamic_widths, app.sort);
+                    Line::styled(header, Style::default().add_modifier(Modifier::BOLD))
                 } else if actual_index == 1 {
                     // Separator
                     Line::from("-".repeat((viewport_width - 2) as usize))
@@ -235,15 +230,29 @@ impl ViewUI for PipelinesView {
 
                     // Apply status styling if we can find it
                     if let Some(status) = parts.get(status_idx) {
-                        style = get_status_style(status);
+                        style = app.theme.status_style(status);
                     }
 
                     // Add selection highlighting
                     if is_selected {
-                        style = style.bg(Color::DarkGray).add_modifier(Modifier::BOLD);
+                        style = app.theme.selected(style);
                     }
 
-                    Line::styled(item.clone(), style)
+                    // Rows that aren't pipelines (e.g. the loading message) are shown as-is
+                    if parts.len() != dynamic_widths.len() {
+                        return Line::styled(item.clone(), style);
+                    }
+
+                    let line = parts
+                        .iter()
+                        .zip(dynamic_widths.iter())
+                        .filter(|&(_, width)| *width > 0)
+                        .map(|(part, &width)| {
+                            format!("{:<width$}", truncate(part, width), width = width)
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    Line::styled(line, style)
                 }
             })
             .collect();
@@ -260,18 +269,18 @@ impl ViewUI for PipelinesView {
         // Footer with keybindings
         let footer_area = Rect::new(area.x, area.y + area.height - 1, area.width, 1);
 
-        let footer = Line::from(vec![
-            Span::styled("↑/k", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(" "),
-            Span::styled("↓/j", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(" Navigate | "),
-            Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(" View Runs | "),
-            Span::styled("/", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(" Search | "),
-            Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
-            Span::raw(" Back"),
-        ]);
+        let footer = key_hints(
+            app,
+            &[
+                (&[Action::Up, Action::Down], "Navigate"),
+                (&[Action::Open], "View Runs"),
+                (&[Action::Search], "Search"),
+                (&[Action::SortColumn], "Sort Column"),
+                (&[Action::SortOrder], "Sort Order"),
+                (&[Action::Back], "Back"),
+                (&[Action::Help], "Help"),
+            ],
+        );
 
         let footer_widget = Paragraph::new(footer).alignment(Alignment::Center);
         f.render_widget(footer_widget, footer_area);
@@ -282,10 +291,8 @@ impl ViewUI for PipelinesView {
         app.items.clear();
 
         // Show loading state
-        app.items.push(
-            "PIPELINE NAME                                        REPOSITORY LOCATION                  LAST RUN STATUS".to_string(),
-        );
-        app.items.push("-".repeat(80));
+        app.items.push("HEADER".to_string());
+        app.items.push("SEPARATOR".to_string());
         app.items.push("Loading pipelines...".to_string());
 
         // Restore previous selection and scroll position if available
@@ -300,10 +307,11 @@ impl ViewUI for PipelinesView {
         // Fetch fresh data
         if let Err(e) = self.fetch_initial_data(app).await {
             log::error!("Failed to load pipelines data: {:?}", e);
-            return Err(Box::new(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("Failed to load data: {}", e),
-            )));
+            app.report_error(e.clone());
+            return Err(Box::new(std::io::Error::other(format!(
+                "Failed to load data: {}",
+                e
+            ))));
         }
 
         Ok(())
@@ -1,5 +1,10 @@
 use crate::app::{App, ConnectionStatus};
+use crate::client::DagsterClient;
+use crate::error::DagsterError;
 use crate::get_run::{get_run, run_query};
+use crate::get_runs::runs_query;
+use crate::keys::Action;
+use crate::utils::run_status_name;
 use crate::views::ViewUI;
 use async_trait::async_trait;
 use ratatui::{
@@ -8,13 +13,14 @@ use ratatui::{
     text::{Line, Span},
     widgets::*,
 };
+use serde::{Serialize, Serializer};
 use std::error::Error;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-use super::ViewPoller;
+use super::{ViewPoller, key_hints};
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize)]
 pub struct Run {
     pub run_id: String,
     pub job_name: String,
@@ -22,6 +28,79 @@ pub struct Run {
     pub run_config_yaml: String,
     pub start_time: Option<f64>,
     pub end_time: Option<f64>,
+    pub mode: String,
+    #[serde(serialize_with = "serialize_tags")]
+    pub tags: Vec<(String, String)>,
+    pub code_location: Option<String>,
+}
+
+/// Tags are kept in Dagster's order, but read better as a map in JSON and YAML
+fn serialize_tags<S: Serializer>(
+    tags: &[(String, String)],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(tags.iter().map(|(key, value)| (key, value)))
+}
+
+impl Run {
+    /// Look up the value of a run tag
+    pub fn tag(&self, key: &str) -> Option<&str> {
+        self.tags
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Describe what launched the run, based on the tags Dagster attaches to it
+    pub fn launched_by(&self) -> Option<String> {
+        if let Some(user) = self.tag("dagster/user") {
+            Some(user.to_string())
+        } else if let Some(schedule) = self.tag("dagster/schedule_name") {
+            Some(format!("schedule:{}", schedule))
+        } else if let Some(sensor) = self.tag("dagster/sensor_name") {
+            Some(format!("sensor:{}", sensor))
+        } else {
+            self.tag("dagster/backfill")
+                .map(|backfill| format!("backfill:{}", backfill))
+        }
+    }
+
+    /// The partition this run targets, if any
+    pub fn partition(&self) -> Option<&str> {
+        self.tag("dagster/partition")
+    }
+}
+
+impl From<run_query::RunQueryRunOrErrorOnRun> for Run {
+    fn from(run: run_query::RunQueryRunOrErrorOnRun) -> Self {
+        Self {
+            run_id: run.run_id,
+            job_name: run.job_name,
+            status: run_status_name(&run.status),
+            run_config_yaml: run.run_config_yaml,
+            start_time: run.start_time,
+            end_time: run.end_time,
+            mode: run.mode,
+            tags: run.tags.into_iter().map(|t| (t.key, t.value)).collect(),
+            code_location: run.repository_origin.map(|o| o.repository_location_name),
+        }
+    }
+}
+
+impl From<runs_query::RunsQueryRunsOrErrorOnRunsResults> for Run {
+    fn from(run: runs_query::RunsQueryRunsOrErrorOnRunsResults) -> Self {
+        Self {
+            run_id: run.run_id,
+            job_name: run.job_name,
+            status: run_status_name(&run.status),
+            run_config_yaml: run.run_config_yaml,
+            start_time: run.start_time,
+            end_time: run.end_time,
+            mode: run.mode,
+            tags: run.tags.into_iter().map(|t| (t.key, t.value)).collect(),
+            code_location: run.repository_origin.map(|o| o.repository_location_name),
+        }
+    }
 }
 
 pub struct RunView {
@@ -41,32 +120,10 @@ impl RunView {
         }
     }
 
-    pub async fn fetch_details(
-        &mut self,
-        dagster_url: &str,
-    ) -> Result<(), Box<dyn Error + Send + Sync>> {
-        match get_run(self.run_id.clone(), dagster_url.to_string()).await {
-            Ok(data) => match data.run_or_error {
-                run_query::RunQueryRunOrError::Run(run_data) => {
-                    self.details = Some(Run {
-                        run_id: run_data.run_id,
-                        job_name: run_data.job_name,
-                        status: format!("{:?}", run_data.status),
-                        run_config_yaml: run_data.run_config_yaml,
-                        start_time: run_data.start_time,
-                        end_time: run_data.end_time,
-                    });
-                    Ok(())
-                }
-                run_query::RunQueryRunOrError::RunNotFoundError(err) => Err(Box::new(
-                    std::io::Error::new(std::io::ErrorKind::Other, err.message),
-                )),
-                run_query::RunQueryRunOrError::PythonError(err) => Err(Box::new(
-                    std::io::Error::new(std::io::ErrorKind::Other, err.message),
-                )),
-            },
-            Err(e) => Err(e),
-        }
+    pub async fn fetch_details(&mut self, client: &DagsterClient) -> Result<(), DagsterError> {
+        let run = get_run(client, self.run_id.clone()).await?;
+        self.details = Some(Run::from(run));
+        Ok(())
     }
 }
 
@@ -78,48 +135,27 @@ impl ViewPoller for RunPoller {
         &self,
         app: Arc<Mutex<App>>,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        let (should_poll, dagster_url, run_id) = {
+        let (client, run_id) = {
             let app_lock = app.lock().await;
             match &app_lock.view {
-                super::ViewType::Run(id) => (true, app_lock.dagster_url.clone(), id.clone()),
-                _ => (false, String::new(), String::new()),
+                super::ViewType::Run(id) => (app_lock.client.clone(), id.clone()),
+                _ => return Ok(()),
             }
         };
 
-        if !should_poll {
-            return Ok(());
-        }
-
-        match get_run(run_id, dagster_url).await {
-            Ok(data) => {
+        match get_run(&client, run_id).await {
+            Ok(run) => {
                 let mut app_lock = app.lock().await;
-                match data.run_or_error {
-                    run_query::RunQueryRunOrError::Run(run_data) => {
-                        if let Some(run_view) = &mut app_lock.run_view {
-                            run_view.details = Some(Run {
-                                run_id: run_data.run_id,
-                                job_name: run_data.job_name,
-                                status: format!("{:?}", run_data.status),
-                                run_config_yaml: run_data.run_config_yaml,
-                                start_time: run_data.start_time,
-                                end_time: run_data.end_time,
-                            });
-                        }
-                        app_lock.connection_status = ConnectionStatus::Connected;
-                    }
-                    run_query::RunQueryRunOrError::RunNotFoundError(err) => {
-                        app_lock.connection_status = ConnectionStatus::Failed(err.message);
-                    }
-                    run_query::RunQueryRunOrError::PythonError(err) => {
-                        app_lock.connection_status = ConnectionStatus::Failed(err.message);
-                    }
+                if let Some(run_view) = &mut app_lock.run_view {
+                    run_view.details = Some(Run::from(run));
                 }
+                app_lock.connection_status = ConnectionStatus::Connected;
                 Ok(())
             }
             Err(e) => {
                 let mut app_lock = app.lock().await;
-                app_lock.connection_status = ConnectionStatus::Failed(e.to_string());
-                Err(e)
+                app_lock.report_error(e.clone());
+                Err(Box::new(e))
             }
         }
     }
@@ -137,7 +173,7 @@ impl ViewPoller for RunView {
 
 #[async_trait::async_trait]
 impl ViewUI for RunView {
-    fn draw(&self, f: &mut Frame, _app: &App, area: Rect) {
+    fn draw(&self, f: &mut Frame, app: &App, area: Rect) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
@@ -148,7 +184,7 @@ impl ViewUI for RunView {
 
         // Render all content in a single block
         if let Some(details) = &self.details {
-            log::debug!("Rendering run details: {}", details.run_id);
+            log::trace!("Rendering run details: {}", details.run_id);
 
             let content_block = Block::default()
                 .borders(Borders::ALL)
@@ -183,10 +219,7 @@ impl ViewUI for RunView {
                         "Status:      ",
                         Style::default().add_modifier(Modifier::BOLD),
                     ),
-                    Span::styled(
-                        &details.status,
-                        crate::utils::get_status_style(&details.status),
-                    ),
+                    Span::styled(&details.status, app.theme.status_style(&details.status)),
                 ]),
                 Line::from(vec![
                     Span::styled(
@@ -231,18 +264,18 @@ impl ViewUI for RunView {
             f.render_widget(content, inner_area);
 
             // Footer with keybindings
-            let footer = Line::from(vec![
-                Span::styled("ESC", Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw(" Back | "),
-                Span::styled("↑/k", Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw(" Scroll Up | "),
-                Span::styled("↓/j", Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw(" Scroll Down | "),
-                Span::styled("←/h", Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw(" Scroll Left | "),
-                Span::styled("→/l", Style::default().add_modifier(Modifier::BOLD)),
-                Span::raw(" Scroll Right"),
-            ]);
+            let footer = key_hints(
+                app,
+                &[
+                    (&[Action::Back], "Back"),
+                    (&[Action::Up], "Scroll Up"),
+                    (&[Action::Down], "Scroll Down"),
+                    (&[Action::Left], "Scroll Left"),
+                    (&[Action::Right], "Scroll Right"),
+                    (&[Action::Delete], "Delete"),
+                    (&[Action::Help], "Help"),
+                ],
+            );
 
             let footer_widget = Paragraph::new(footer).alignment(Alignment::Center);
             f.render_widget(footer_widget, chunks[1]);
@@ -1,19 +1,24 @@
-use crate::app::{App, ColumnsConfig, ConnectionStatus};
-use crate::get_runs::{Variables, get_runs, runs_query};
-use crate::utils::{format_duration, format_timestamp, get_status_style, truncate};
+use crate::app::{App, ColumnsConfig, ConnectionStatus, SortColumn, SortState};
+use crate::config::RunColumn;
+use crate::error::DagsterError;
+use crate::get_runs::{Variables, get_runs};
+use crate::keys::Action;
+use crate::utils::{format_duration, format_timestamp, truncate};
 use crate::views::ViewType;
 use async_trait::async_trait;
+use chrono::Utc;
 use ratatui::{
     prelude::*,
-    style::{Color, Modifier, Style},
-    text::{Line, Span},
+    style::{Modifier, Style},
+    text::Line,
     widgets::*,
 };
+use std::cmp::Ordering;
 use std::error::Error;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-use super::{Run, ViewPoller, ViewUI};
+use super::{Run, ViewPoller, ViewUI, key_hints};
 
 pub struct RunsView;
 
@@ -22,10 +27,7 @@ impl RunsView {
         Self {}
     }
 
-    pub async fn fetch_initial_data(
-        &self,
-        app: &mut App,
-    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    pub async fn fetch_initial_data(&self, app: &mut App) -> Result<(), DagsterError> {
         let runs = self.fetch_runs(app).await?;
 
         let mut state = app.state.lock().await;
@@ -36,10 +38,7 @@ impl RunsView {
         Ok(())
     }
 
-    pub async fn fetch_runs(
-        &self,
-        app: &App,
-    ) -> Result<Vec<Run>, Box<dyn std::error::Error + Send + Sync>> {
+    pub async fn fetch_runs(&self, app: &App) -> Result<Vec<Run>, DagsterError> {
         // Get pipeline_name from view if we're in PipelineRuns view
         let pipeline_name = match &app.view {
             ViewType::PipelineRuns(name) => name.clone(),
@@ -54,29 +53,11 @@ impl RunsView {
             pipeline_name,
             cursor: app.state.lock().await.cursor.clone(),
             run_ids: vec![],
+            statuses: app.run_statuses.clone(),
         };
         let runs_limit = app.config.get_current_context().runs_limit;
-        match get_runs(variables, app.dagster_url.clone(), runs_limit).await {
-            Ok(data) => {
-                let runs = match data.runs_or_error {
-                    runs_query::RunsQueryRunsOrError::Runs(runs_data) => runs_data
-                        .results
-                        .into_iter()
-                        .map(|run| Run {
-                            run_id: run.run_id,
-                            job_name: run.job_name,
-                            status: format!("{:?}", run.status),
-                            run_config_yaml: run.run_config_yaml,
-                            start_time: run.start_time,
-                            end_time: run.end_time,
-                        })
-                        .collect(),
-                    _ => vec![],
-                };
-                Ok(runs)
-            }
-            Err(e) => Err(e),
-        }
+        let runs = get_runs(&app.client, variables, runs_limit).await?;
+        Ok(runs.into_iter().map(Run::from).collect())
     }
 
     // Populate a target vector
@@ -84,30 +65,13 @@ impl RunsView {
     pub async fn populate_runs_items_into(&self, app: &App, target: &mut Vec<String>) {
         log::debug!("populate_runs_items_into: Starting population");
 
-        let runs = {
-            let state_lock = app.state.lock().await;
-            state_lock.runs.clone()
-        };
-
-        // Format runs into items
-        let items = runs
-            .iter()
-            .map(|r| {
-                vec![
-                    r.run_id.clone(),
-                    r.job_name.clone(),
-                    r.status.clone(),
-                    format_duration(r.start_time, r.end_time),
-                    format_timestamp(r.start_time),
-                ]
-            })
-            .collect::<Vec<Vec<String>>>();
+        let rows = run_rows(app).await;
 
         // Clear target and fill with new data
         target.clear();
         target.push("HEADER".to_string());
         target.push("SEPARATOR".to_string());
-        target.extend(items.into_iter().map(|row| row.join(" ")));
+        target.extend(rows);
 
         log::debug!(
             "populate_runs_items_into: Created {} formatted items for UI",
@@ -116,30 +80,13 @@ impl RunsView {
     }
     pub async fn populate_runs_items(&self, app: &mut App) {
         // We can't borrow app and app.items simultaneously, so:
-        let runs = {
-            let state_lock = app.state.lock().await;
-            state_lock.runs.clone()
-        };
-
-        // Now create the formatted items directly
-        let items = runs
-            .iter()
-            .map(|r| {
-                vec![
-                    r.run_id.clone(),
-                    r.job_name.clone(),
-                    r.status.clone(),
-                    format_duration(r.start_time, r.end_time),
-                    format_timestamp(r.start_time),
-                ]
-            })
-            .collect::<Vec<Vec<String>>>();
+        let rows = run_rows(app).await;
 
         // Create the final items vector with header and separator
         app.items.clear();
         app.items.push("HEADER".to_string());
         app.items.push("SEPARATOR".to_string());
-        app.items.extend(items.into_iter().map(|row| row.join(" ")));
+        app.items.extend(rows);
 
         log::debug!(
             "populate_runs_items: Created {} formatted items for UI",
@@ -148,18 +95,125 @@ impl RunsView {
     }
 }
 
+/// Separates cells within a run row. Cells such as tags and relative start times
+/// contain spaces, so rows can't be split on whitespace. Every row starts with the
+/// run ID as a hidden key cell, so the run can be found whichever columns are shown.
+const CELL_SEPARATOR: char = '\t';
+
+/// The run ID of a runs table row, or None for rows that aren't runs (e.g. the loading message)
+pub fn row_run_id(item: &str) -> Option<&str> {
+    item.split_once(CELL_SEPARATOR).map(|(run_id, _)| run_id)
+}
+
+/// Formats the cached runs into sorted rows for the current context's columns
+async fn run_rows(app: &App) -> Vec<String> {
+    let mut runs = {
+        let state_lock = app.state.lock().await;
+        state_lock.runs.clone()
+    };
+    let columns = app.config.get_current_context().runs_columns();
+    sort_runs(&mut runs, app.sort, &columns);
+
+    runs.iter().map(|run| run_row(run, &columns)).collect()
+}
+
+/// Formats a run as a runs table row. This is also the text searches match against
+pub fn run_row(run: &Run, columns: &[RunColumn]) -> String {
+    std::iter::once(run.run_id.clone())
+        .chain(
+            columns
+                .iter()
+                .map(|&column| run_cell(run, column).replace(['\t', '\n'], " ")),
+        )
+        .collect::<Vec<_>>()
+        .join(&CELL_SEPARATOR.to_string())
+}
+
+/// Formats a single cell of the runs table
+pub fn run_cell(run: &Run, column: RunColumn) -> String {
+    match column {
+        RunColumn::RunId => run.run_id.clone(),
+        RunColumn::Pipeline => run.job_name.clone(),
+        RunColumn::Status => run.status.clone(),
+        RunColumn::Duration => format_duration(run.start_time, run.end_time),
+        RunColumn::StartTime => format_timestamp(run.start_time),
+        RunColumn::EndTime => format_timestamp(run.end_time),
+        RunColumn::Tags => {
+            // Tags starting with '.' are hidden by Dagster itself
+            let tags = run
+                .tags
+                .iter()
+                .filter(|(key, _)| !key.starts_with('.'))
+                .map(|(key, value)| format!("{}={}", key, value))
+                .collect::<Vec<_>>();
+            if tags.is_empty() {
+                "-".to_string()
+            } else {
+                tags.join(", ")
+            }
+        }
+        RunColumn::LaunchedBy => run.launched_by().unwrap_or_else(|| "-".to_string()),
+        RunColumn::Partition => run.partition().unwrap_or("-").to_string(),
+        RunColumn::CodeLocation => run.code_location.clone().unwrap_or_else(|| "-".to_string()),
+        RunColumn::Mode => run.mode.clone(),
+    }
+}
+
+/// Orders runs by one of the displayed columns, keeping server order for ties
+fn sort_runs(runs: &mut [Run], sort: Option<SortState>, columns: &[RunColumn]) {
+    // Columns no longer shown keep server order
+    let Some(SortState {
+        column: SortColumn::Run(column),
+        descending,
+    }) = sort
+    else {
+        return;
+    };
+    if !columns.contains(&column) {
+        return;
+    }
+
+    let now = Utc::now().timestamp() as f64;
+    let duration = |run: &Run| {
+        run.start_time
+            .map(|start| run.end_time.unwrap_or(now) - start)
+    };
+
+    runs.sort_by(|a, b| {
+        let ordering = match column {
+            RunColumn::Duration => duration(a)
+                .partial_cmp(&duration(b))
+                .unwrap_or(Ordering::Equal),
+            RunColumn::StartTime => a
+                .start_time
+                .partial_cmp(&b.start_time)
+                .unwrap_or(Ordering::Equal),
+            RunColumn::EndTime => a
+                .end_time
+                .partial_cmp(&b.end_time)
+                .unwrap_or(Ordering::Equal),
+            _ => run_cell(a, column).cmp(&run_cell(b, column)),
+        };
+        if descending {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+}
+
 #[async_trait]
 impl ViewPoller for RunsView {
     async fn poll(
         &self,
         app: Arc<Mutex<App>>,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        let (should_poll, dagster_url, runs_limit) = {
+        let (should_poll, client, runs_limit) = {
             let app_lock = app.lock().await;
             (
                 matches!(app_lock.view, super::ViewType::Runs)
                     || matches!(app_lock.view, super::ViewType::PipelineRuns(_)),
-                app_lock.dagster_url.clone(),
+                app_lock.client.clone(),
                 app_lock.config.get_current_context().runs_limit,
             )
         };
@@ -170,78 +224,68 @@ impl ViewPoller for RunsView {
         log::debug!("RunsPoller: Starting poll");
 
         // Get view type to determine if we're filtering by pipeline
-        let (cursor, pipeline_name) = {
+        let (cursor, pipeline_name, statuses) = {
             let app_lock = app.lock().await;
             let state = app_lock.state.lock().await;
             let pipeline_name = match &app_lock.view {
                 ViewType::PipelineRuns(name) => name.clone(),
                 _ => state.selected_pipeline.clone().unwrap_or_default(),
             };
-            (state.cursor.clone(), pipeline_name)
+            (
+                state.cursor.clone(),
+                pipeline_name,
+                app_lock.run_statuses.clone(),
+            )
         };
 
         let variables = Variables {
             pipeline_name,
             cursor,
             run_ids: vec![],
+            statuses,
         };
 
-        let result = get_runs(variables, dagster_url, runs_limit).await;
+        let result = get_runs(&client, variables, runs_limit).await;
 
         match result {
-            Ok(data) => {
-                let runs = match data.runs_or_error {
-                    runs_query::RunsQueryRunsOrError::Runs(runs_data) => runs_data
-                        .results
-                        .into_iter()
-                        .map(|run| Run {
-                            run_id: run.run_id,
-                            job_name: run.job_name,
-                            status: format!("{:?}", run.status),
-                            run_config_yaml: run.run_config_yaml,
-                            start_time: run.start_time,
-                            end_time: run.end_time,
-                        })
-                        .collect(),
-                    _ => vec![],
-                };
-
-                if !runs.is_empty() {
-                    let mut app_lock = app.lock().await;
-
-                    // Only update if we're still in the Runs or PipelineRuns view
-                    if !matches!(app_lock.view, super::ViewType::Runs)
-                        && !matches!(app_lock.view, super::ViewType::PipelineRuns(_))
-                    {
-                        return Ok(());
-                    }
+            Ok(runs) => {
+                let runs: Vec<Run> = runs.into_iter().map(Run::from).collect();
 
-                    app_lock.connection_status = ConnectionStatus::Connected;
+                let mut app_lock = app.lock().await;
 
-                    // Check if there's an active filter - either in search mode or with a committed filter
-                    let has_filter = app_lock.search_mode || app_lock.has_committed_filter;
+                // Only update if we're still in the Runs or PipelineRuns view
+                if !matches!(app_lock.view, super::ViewType::Runs)
+                    && !matches!(app_lock.view, super::ViewType::PipelineRuns(_))
+                {
+                    return Ok(());
+                }
 
-                    {
-                        let mut state = app_lock.state.lock().await;
-                        state.runs = runs;
-                    }
+                app_lock.connection_status = ConnectionStatus::Connected;
 
-                    if has_filter {
-                        // If there's an active filter, update unfiltered_items first
-                        // then re-apply the filter
-                        let mut unfiltered_items = Vec::new();
-                        // Update a temporary vector
-                        self.populate_runs_items_into(&app_lock, &mut unfiltered_items)
-                            .await;
-                        // Assign it back
-                        app_lock.unfiltered_items = unfiltered_items;
-
-                        // Reapply the search filter
-                        app_lock.apply_search_filter();
-                    } else {
-                        // No active filter, update items directly
-                        self.populate_runs_items(&mut app_lock).await;
-                    }
+                // Check if there's an active filter - either in search mode or with a committed filter
+                let has_filter = app_lock.search_mode || app_lock.has_committed_filter;
+
+                // An empty result is a real answer too, e.g. once every listed run is deleted
+                {
+                    let mut state = app_lock.state.lock().await;
+                    state.runs = runs;
+                }
+
+                if has_filter {
+                    // If there's an active filter, update unfiltered_items first
+                    // then re-apply the filter
+                    let mut unfiltered_items = Vec::new();
+                    // Update a temporary vector
+                    self.populate_runs_items_into(&app_lock, &mut unfiltered_items)
+                        .await;
+                    // Assign it back
+                    app_lock.unfiltered_items = unfiltered_items;
+
+                    // Reapply the search filter
+                    app_lock.apply_search_filter();
+                } else {
+                    // No active filter, update items directly
+                    self.populate_runs_items(&mut app_lock).await;
                 }
                 Ok(())
             }
@@ -252,9 +296,9 @@ impl ViewPoller for RunsView {
                 if matches!(app_lock.view, super::ViewType::Runs)
                     || matches!(app_lock.view, super::ViewType::PipelineRuns(_))
                 {
-                    app_lock.connection_status = ConnectionStatus::Failed(e.to_string());
+                    app_lock.report_error(e.clone());
                 }
-                Err(e)
+                Err(Box::new(e))
             }
         }
     }
@@ -263,115 +307,123 @@ impl ViewPoller for RunsView {
 #[async_trait::async_trait]
 impl ViewUI for RunsView {
     fn draw(&self, f: &mut Frame, app: &App, area: Rect) {
-    let viewport_height = area.height as usize;
-    let viewport_width = area.width;
-
-    let columns_config = ColumnsConfig::new();
-    let dynamic_widths = columns_config.calculate_widths(viewport_width - 2); // Account for borders
-    let columns = columns_config.get_ordered_columns();
-
-    // Create spans for each visible item
-    let visible_items: Vec<Line> = app
-        .items
-        .iter()
-        .skip(app.list_offset) // Skip items above viewport
-        .take(viewport_height as usize) // Take only what fits in viewport
-        .enumerate()
-        .map(|(i, item)| {
-            let actual_index = i + app.list_offset;
-            let is_selected = actual_index == app.selected_index;
-
-            if actual_index == 0 {
-                // Header
-                let header = columns
-                    .iter()
-                    .zip(dynamic_widths.iter())
-                    .filter(|&(_, width)| *width > 0)
-                    .map(|(&col, &width)| format!("{:<width$}", col.name, width = width))
-                    .collect::<Vec<_>>()
-                    .join(" ");
-                Line::styled(header, Style::default().add_modifier(Modifier::BOLD))
-            } else if actual_index == 1 {
-                // Separator
-                Line::from("-".repeat((viewport_width - 2) as usize))
-            } else {
-                // Instead of splitting by whitespace, parse the Run data structure format
-                // A run consists of: run_id, job_name, status, duration, start_time
-                // We know the first 3 fields won't have spaces, so we can extract them
-                let parts: Vec<&str> = item.splitn(5, ' ').collect();
-                
-                let mut style = if parts.len() > 2 {
-                    // Get style based on status (the 3rd field)
-                    get_status_style(parts[2])
+        let viewport_height = area.height as usize;
+        let viewport_width = area.width;
+
+        let run_columns = app.config.get_current_context().runs_columns();
+        let columns_config = ColumnsConfig::new(&run_columns);
+        let dynamic_widths = columns_config.calculate_widths(viewport_width - 2); // Account for borders
+        let status_idx = run_columns.iter().position(|&c| c == RunColumn::Status);
+
+        // Create spans for each visible item
+        let visible_items: Vec<Line> = app
+            .items
+            .iter()
+            .skip(app.list_offset) // Skip items above viewport
+            .take(viewport_height) // Take only what fits in viewport
+            .enumerate()
+            .map(|(i, item)| {
+                let actual_index = i + app.list_offset;
+                let is_selected = actual_index == app.selected_index;
+
+                if actual_index == 0 {
+                    // Header
+                    let header = columns_config.header(&dynamic_widths, app.sort);
+                    Line::styled(header, Style::default().add_modifier(Modifier::BOLD))
+                } else if actual_index == 1 {
+                    // Separator
+                    Line::from("-".repeat((viewport_width - 2) as usize))
                 } else {
-                    Style::default()
-                };
+                    // Skip the hidden run ID key cell
+                    let parts: Vec<&str> = item.split(CELL_SEPARATOR).skip(1).collect();
+
+                    // Get style based on status, if that column is shown
+                    let mut style = match status_idx.and_then(|idx| parts.get(idx)) {
+                        Some(status) => app.theme.status_style(status),
+                        None => Style::default(),
+                    };
+
+                    // Marked runs and the visual range stand out from status colors
+                    let is_marked = row_run_id(item)
+                        .is_some_and(|run_id| app.marked_runs.contains(run_id))
+                        || app.in_visual_range(actual_index);
+                    if is_marked {
+                        style = style.fg(app.theme.highlight).add_modifier(Modifier::BOLD);
+                    }
 
-                if is_selected {
-                    style = style.bg(Color::DarkGray).add_modifier(Modifier::BOLD);
-                }
-                
-                // Format the display string based on column widths
-                let mut formatted = Vec::new();
-                
-                for (idx, &width) in dynamic_widths.iter().enumerate() {
-                    if width > 0 && idx < parts.len() {
-                        let content = if idx == 4 {
-                            // For the start_time field, use the rest of the string
-                            // to avoid breaking up the timestamp format
-                            parts[4]
-                        } else {
-                            parts[idx]
-                        };
-                        
-                        formatted.push(format!(
-                            "{:<width$}",
-                            truncate(content, width),
-                            width = width
-                        ));
+                    if is_selected {
+                        style = app.theme.selected(style);
+                    }
+
+                    // Rows that aren't runs (e.g. the loading message) are shown as-is
+                    if parts.len() != dynamic_widths.len() {
+                        return Line::styled(item.clone(), style);
                     }
+
+                    // Format the display string based on column widths
+                    let line = parts
+                        .iter()
+                        .zip(dynamic_widths.iter())
+                        .filter(|&(_, width)| *width > 0)
+                        .map(|(part, &width)| {
+                            format!("{:<width$}", truncate(part, width), width = width)
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    Line::styled(line, style)
                 }
+            })
+            .collect();
 
-                let line = formatted.join(" ");
-                Line::styled(line, style)
+        let mut title = match &app.view {
+            ViewType::PipelineRuns(pipeline_name) => {
+                format!(" Runs for Pipeline: {} ", pipeline_name)
             }
-        })
-        .collect();
-
-    let title = match &app.view {
-        ViewType::PipelineRuns(pipeline_name) => {
-            format!(" Runs for Pipeline: {} ", pipeline_name)
+            _ => " All Runs ".to_string(),
+        };
+        if !app.run_statuses.is_empty() {
+            title.push_str(&format!("[{}] ", app.run_statuses.join("|")));
+        }
+        if app.visual_anchor.is_some() {
+            title.push_str("[VISUAL] ");
+        }
+        if !app.marked_runs.is_empty() {
+            title.push_str(&format!("[{} marked] ", app.marked_runs.len()));
         }
-        _ => " All Runs ".to_string(),
-    };
 
-    let paragraph = Paragraph::new(visible_items).block(
-        Block::default()
-            .borders(Borders::ALL)
-            .title(title)
-            .title_alignment(Alignment::Center),
-    );
-    f.render_widget(paragraph, area);
-
-    // Footer with keybindings
-    let footer_area = Rect::new(area.x, area.y + area.height - 1, area.width, 1);
-
-    let footer = Line::from(vec![
-        Span::styled("↑/k", Style::default().add_modifier(Modifier::BOLD)),
-        Span::raw(" "),
-        Span::styled("↓/j", Style::default().add_modifier(Modifier::BOLD)),
-        Span::raw(" Navigate | "),
-        Span::styled("Enter", Style::default().add_modifier(Modifier::BOLD)),
-        Span::raw(" View Details | "),
-        Span::styled("/", Style::default().add_modifier(Modifier::BOLD)),
-        Span::raw(" Search | "),
-        Span::styled("Esc", Style::default().add_modifier(Modifier::BOLD)),
-        Span::raw(" Back"),
-    ]);
-
-    let footer_widget = Paragraph::new(footer).alignment(Alignment::Center);
-    f.render_widget(footer_widget, footer_area);
-}
+        let paragraph = Paragraph::new(visible_items).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(title)
+                .title_alignment(Alignment::Center),
+        );
+        f.render_widget(paragraph, area);
+
+        // Footer with keybindings
+        let footer_area = Rect::new(area.x, area.y + area.height - 1, area.width, 1);
+
+        let footer = key_hints(
+            app,
+            &[
+                (&[Action::Up, Action::Down], "Navigate"),
+                (&[Action::Open], "View Details"),
+                (&[Action::Search], "Search"),
+                (&[Action::Mark, Action::Visual], "Mark"),
+                (
+                    &[Action::Terminate, Action::Reexecute, Action::Delete],
+                    "Terminate/Re-execute/Delete",
+                ),
+                (&[Action::Copy], "Copy IDs"),
+                (&[Action::SortColumn], "Sort Column"),
+                (&[Action::SortOrder], "Sort Order"),
+                (&[Action::Back], "Back"),
+                (&[Action::Help], "Help"),
+            ],
+        );
+
+        let footer_widget = Paragraph::new(footer).alignment(Alignment::Center);
+        f.render_widget(footer_widget, footer_area);
+    }
     async fn restore_state(&self, app: &mut App) -> Result<(), Box<dyn Error + Send + Sync>> {
         // Clear any existing data
         app.items.clear();
@@ -393,10 +445,11 @@ impl ViewUI for RunsView {
         // Fetch fresh data
         if let Err(e) = self.fetch_initial_data(app).await {
             log::error!("Failed to load runs data: {:?}", e);
-            return Err(Box::new(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("Failed to load data: {}", e),
-            )));
+            app.report_error(e.clone());
+            return Err(Box::new(std::io::Error::other(format!(
+                "Failed to load data: {}",
+                e
+            ))));
         }
 
         Ok(())